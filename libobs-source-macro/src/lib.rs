@@ -112,12 +112,13 @@ pub fn obs_object_updater(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// This macro is used to generate a builder pattern for an obs source. <br>
 /// The attribute should be the id of the source.<br>
 /// The struct should have named fields, each field should have an attribute `#[obs_property(type_t="your_type")]`. <br>
-/// `type_t` can be `enum`, `enum_string`, `string`, `bool` or `int`. <br>
+/// `type_t` can be `enum`, `enum_string`, `string`, `bool`, `int` or `double`. <br>
 /// - `enum`: the field should be an enum with `num_derive::{FromPrimitive, ToPrimitive}`.
 /// - `enum_string`: the field should be an enum which implements `StringEnum`.
 /// - `string`: the field should be a string.
 /// - `bool`: the field should be a bool.
 /// - `type_t`: `int`, the field should be an i64.
+/// - `type_t`: `double`, the field should be an f64.
 ///   The attribute can also have a `settings_key` which is the key used in the settings, if this attribute is not given, the macro defaults to the field name.
 ///
 /// Documentation is inherited from the field to the setter function.
@@ -124,8 +124,18 @@ pub fn obs_properties_to_functions(
                     }
                 }
             }
+            "double" => {
+                quote! {
+                    #(#docs_attr)*
+                    pub fn #set_field(mut self, #field_name: f64) -> Self {
+                        #settings_getter
+                            .set_double_ref(#obs_settings_key, #field_name);
+                        self
+                    }
+                }
+            }
             _ => panic!(
-                "Unsupported type_t {}. Should either be `enum`, `string`, `bool` or `int`",
+                "Unsupported type_t {}. Should either be `enum`, `string`, `bool`, `int` or `double`",
                 type_t
             ),
         };
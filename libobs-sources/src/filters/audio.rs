@@ -0,0 +1,205 @@
+use libobs_wrapper::{
+    data::StringEnum,
+    sources::{ObsFilterBuilder, ObsSourceRef},
+};
+
+use crate::macro_helper::define_object_manager;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The algorithm used by the noise suppression filter.
+pub enum ObsNoiseSuppressMethod {
+    /// Low CPU usage, but lower quality. Uses `suppress_level`
+    Speex,
+    /// Good quality with more CPU usage
+    RNNoise,
+    /// NVIDIA Noise Removal, requires an RTX GPU. Uses `intensity`
+    NvidiaDenoiser,
+}
+
+impl StringEnum for ObsNoiseSuppressMethod {
+    fn to_str(&self) -> &str {
+        match self {
+            ObsNoiseSuppressMethod::Speex => "speex",
+            ObsNoiseSuppressMethod::RNNoise => "rnnoise",
+            ObsNoiseSuppressMethod::NvidiaDenoiser => "denoiser",
+        }
+    }
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Removes background noise from an audio source.
+    struct NoiseSuppressionFilter("noise_suppress_filter_v2") for ObsSourceRef {
+        #[obs_property(type_t = "enum_string")]
+        /// The suppression algorithm.
+        method: ObsNoiseSuppressMethod,
+
+        #[obs_property(type_t = "int")]
+        /// The suppression level in dB for Speex, ranging from -60 to 0.
+        suppress_level: i64,
+
+        #[obs_property(type_t = "double")]
+        /// The suppression intensity for NVIDIA Noise Removal, ranging from 0 to 1.
+        intensity: f64,
+    }
+);
+
+impl ObsFilterBuilder for NoiseSuppressionFilterBuilder {}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Mutes an audio source while its volume is below a threshold.
+    struct NoiseGateFilter("noise_gate_filter") for ObsSourceRef {
+        #[obs_property(type_t = "double")]
+        /// The volume in dB above which the gate opens, ranging from -96 to 0.
+        open_threshold: f64,
+
+        #[obs_property(type_t = "double")]
+        /// The volume in dB below which the gate closes, ranging from -96 to 0.
+        close_threshold: f64,
+
+        #[obs_property(type_t = "int")]
+        /// Time in milliseconds until the gate is fully open.
+        attack_time: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Time in milliseconds the gate stays open after the volume fell below the close threshold.
+        hold_time: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Time in milliseconds until the gate is fully closed.
+        release_time: i64,
+    }
+);
+
+impl ObsFilterBuilder for NoiseGateFilterBuilder {}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Amplifies or attenuates an audio source.
+    struct GainFilter("gain_filter") for ObsSourceRef {
+        #[obs_property(type_t = "double", settings_key = "db")]
+        /// The gain in dB, ranging from -30 to 30.
+        gain: f64,
+    }
+);
+
+impl ObsFilterBuilder for GainFilterBuilder {}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Reduces the dynamic range of an audio source by lowering the volume above a threshold.
+    struct CompressorFilter("compressor_filter") for ObsSourceRef {
+        #[obs_property(type_t = "double")]
+        /// The compression ratio, ranging from 1 to 32.
+        ratio: f64,
+
+        #[obs_property(type_t = "double")]
+        /// The volume in dB above which the audio is compressed, ranging from -60 to 0.
+        threshold: f64,
+
+        #[obs_property(type_t = "int")]
+        /// Time in milliseconds until the compression is fully applied.
+        attack_time: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Time in milliseconds until the compression is fully released.
+        release_time: i64,
+
+        #[obs_property(type_t = "double")]
+        /// The gain in dB applied after compressing, ranging from -32 to 32.
+        output_gain: f64,
+
+        #[obs_property(type_t = "string")]
+        /// The name of the audio source used for ducking. Leave empty to compress based on
+        /// the volume of the source itself.
+        sidechain_source: String,
+    }
+);
+
+impl ObsFilterBuilder for CompressorFilterBuilder {}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Prevents an audio source from getting louder than a threshold.
+    struct LimiterFilter("limiter_filter") for ObsSourceRef {
+        #[obs_property(type_t = "double")]
+        /// The maximum volume in dB, ranging from -60 to 0.
+        threshold: f64,
+
+        #[obs_property(type_t = "int")]
+        /// Time in milliseconds until the limiting is fully released.
+        release_time: i64,
+    }
+);
+
+impl ObsFilterBuilder for LimiterFilterBuilder {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The preset of the expander filter.
+pub enum ObsExpanderPreset {
+    Expander,
+    Gate,
+}
+
+impl StringEnum for ObsExpanderPreset {
+    fn to_str(&self) -> &str {
+        match self {
+            ObsExpanderPreset::Expander => "expander",
+            ObsExpanderPreset::Gate => "gate",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// How the expander filter measures the volume.
+pub enum ObsExpanderDetector {
+    Rms,
+    Peak,
+}
+
+impl StringEnum for ObsExpanderDetector {
+    fn to_str(&self) -> &str {
+        match self {
+            ObsExpanderDetector::Rms => "RMS",
+            ObsExpanderDetector::Peak => "peak",
+        }
+    }
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Lowers the volume of an audio source below a threshold, which reduces background noise
+    /// more smoothly than a noise gate.
+    struct ExpanderFilter("expander_filter") for ObsSourceRef {
+        #[obs_property(type_t = "enum_string", settings_key = "presets")]
+        /// Sets the defaults of the expander or gate preset.
+        preset: ObsExpanderPreset,
+
+        #[obs_property(type_t = "double")]
+        /// The expansion ratio, ranging from 1 to 20.
+        ratio: f64,
+
+        #[obs_property(type_t = "double")]
+        /// The volume in dB below which the audio is expanded, ranging from -60 to 0.
+        threshold: f64,
+
+        #[obs_property(type_t = "int")]
+        /// Time in milliseconds until the expansion is fully applied.
+        attack_time: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Time in milliseconds until the expansion is fully released.
+        release_time: i64,
+
+        #[obs_property(type_t = "double")]
+        /// The gain in dB applied after expanding, ranging from -32 to 32.
+        output_gain: f64,
+
+        #[obs_property(type_t = "enum_string")]
+        /// How the volume is measured.
+        detector: ObsExpanderDetector,
+    }
+);
+
+impl ObsFilterBuilder for ExpanderFilterBuilder {}
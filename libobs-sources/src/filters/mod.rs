@@ -0,0 +1,9 @@
+//! Builders for the video and audio filters of the `obs-filters` plugin.
+//!
+//! Filters are created with `ObsContext::filter_builder` and added to a source with
+//! `ObsFilterBuilder::add_to_source`.
+mod audio;
+pub use audio::*;
+
+mod video;
+pub use video::*;
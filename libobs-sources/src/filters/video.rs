@@ -0,0 +1,377 @@
+use std::path::Path;
+
+use libobs_source_macro::obs_object_impl;
+use libobs_wrapper::{
+    data::StringEnum,
+    sources::{ObsFilterBuilder, ObsSourceRef},
+};
+
+use crate::{macro_helper::define_object_manager, sources::ObsColor};
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Adjusts the colors of a source.
+    struct ColorCorrectionFilter("color_filter_v2") for ObsSourceRef {
+        #[obs_property(type_t = "double")]
+        /// Ranging from -3 to 3, 0 being unchanged.
+        gamma: f64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from -4 to 4, 0 being unchanged.
+        contrast: f64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from -1 to 1, 0 being unchanged.
+        brightness: f64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from -1 to 5, 0 being unchanged.
+        saturation: f64,
+
+        #[obs_property(type_t = "double")]
+        /// Rotates the hue by the given degrees, ranging from -180 to 180.
+        hue_shift: f64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from 0 to 1.
+        opacity: f64,
+
+        #[obs_property(type_t = "int", settings_key = "color_multiply")]
+        /// The color the source is multiplied with in the `0xAABBGGRR` format. Use `set_color_multiply` instead.
+        color_multiply_raw: i64,
+
+        #[obs_property(type_t = "int", settings_key = "color_add")]
+        /// The color added to the source in the `0xAABBGGRR` format. Use `set_color_add` instead.
+        color_add_raw: i64,
+    }
+);
+
+#[obs_object_impl]
+impl ColorCorrectionFilter {
+    pub fn set_color_multiply(self, color: ObsColor) -> Self {
+        self.set_color_multiply_raw(color.to_abgr())
+    }
+
+    pub fn set_color_add(self, color: ObsColor) -> Self {
+        self.set_color_add_raw(color.to_abgr())
+    }
+}
+
+impl ObsFilterBuilder for ColorCorrectionFilterBuilder {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The color that is keyed out by the chroma key and color key filters.
+pub enum ObsKeyColorType {
+    Green,
+    Blue,
+    /// Only supported by the color key filter
+    Red,
+    Magenta,
+    /// Uses the color set with `set_key_color`
+    Custom,
+}
+
+impl StringEnum for ObsKeyColorType {
+    fn to_str(&self) -> &str {
+        match self {
+            ObsKeyColorType::Green => "green",
+            ObsKeyColorType::Blue => "blue",
+            ObsKeyColorType::Red => "red",
+            ObsKeyColorType::Magenta => "magenta",
+            ObsKeyColorType::Custom => "custom",
+        }
+    }
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Removes a background color (e.g. a green screen) from a source, keeping soft edges and
+    /// reducing color spill.
+    struct ChromaKeyFilter("chroma_key_filter_v2") for ObsSourceRef {
+        #[obs_property(type_t = "enum_string")]
+        /// The color to key out.
+        key_color_type: ObsKeyColorType,
+
+        #[obs_property(type_t = "int", settings_key = "key_color")]
+        /// The custom key color in the `0xAABBGGRR` format. Use `set_key_color` instead.
+        key_color_raw: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Ranging from 1 to 1000.
+        similarity: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Ranging from 1 to 1000.
+        smoothness: i64,
+
+        #[obs_property(type_t = "int", settings_key = "spill")]
+        /// Key color spill reduction, ranging from 1 to 1000.
+        spill_reduction: i64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from 0 to 1.
+        opacity: f64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from -4 to 4.
+        contrast: f64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from -1 to 1.
+        brightness: f64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from -1 to 1.
+        gamma: f64,
+    }
+);
+
+#[obs_object_impl]
+impl ChromaKeyFilter {
+    /// Keys out the given color. This sets the key color type to `ObsKeyColorType::Custom`.
+    pub fn set_key_color(self, color: ObsColor) -> Self {
+        self.set_key_color_type(ObsKeyColorType::Custom)
+            .set_key_color_raw(color.to_abgr())
+    }
+}
+
+impl ObsFilterBuilder for ChromaKeyFilterBuilder {}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Makes a color of a source transparent. Use the chroma key filter for green screens.
+    struct ColorKeyFilter("color_key_filter_v2") for ObsSourceRef {
+        #[obs_property(type_t = "enum_string")]
+        /// The color to key out.
+        key_color_type: ObsKeyColorType,
+
+        #[obs_property(type_t = "int", settings_key = "key_color")]
+        /// The custom key color in the `0xAABBGGRR` format. Use `set_key_color` instead.
+        key_color_raw: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Ranging from 1 to 1000.
+        similarity: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Ranging from 1 to 1000.
+        smoothness: i64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from 0 to 1.
+        opacity: f64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from -4 to 4.
+        contrast: f64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from -1 to 1.
+        brightness: f64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from -1 to 1.
+        gamma: f64,
+    }
+);
+
+#[obs_object_impl]
+impl ColorKeyFilter {
+    /// Keys out the given color. This sets the key color type to `ObsKeyColorType::Custom`.
+    pub fn set_key_color(self, color: ObsColor) -> Self {
+        self.set_key_color_type(ObsKeyColorType::Custom)
+            .set_key_color_raw(color.to_abgr())
+    }
+}
+
+impl ObsFilterBuilder for ColorKeyFilterBuilder {}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Crops a source, or pads it if negative values are used.
+    struct CropPadFilter("crop_filter") for ObsSourceRef {
+        #[obs_property(type_t = "bool")]
+        /// If set, `left`, `top`, `right` and `bottom` are used. Otherwise the source is
+        /// cropped to `cx` x `cy` starting at `left` and `top`.
+        relative: bool,
+
+        #[obs_property(type_t = "int")]
+        /// Pixels to crop from the left. Negative values add padding.
+        left: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Pixels to crop from the top. Negative values add padding.
+        top: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Pixels to crop from the right. Negative values add padding.
+        right: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Pixels to crop from the bottom. Negative values add padding.
+        bottom: i64,
+
+        #[obs_property(type_t = "int")]
+        /// The width of the cropped area, if `relative` is not set.
+        cx: i64,
+
+        #[obs_property(type_t = "int")]
+        /// The height of the cropped area, if `relative` is not set.
+        cy: i64,
+    }
+);
+
+impl ObsFilterBuilder for CropPadFilterBuilder {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The algorithm the scaling filter uses.
+pub enum ObsScaleSampling {
+    Point,
+    Bilinear,
+    Bicubic,
+    Lanczos,
+    /// Area averaging, best for downscaling
+    Area,
+}
+
+impl StringEnum for ObsScaleSampling {
+    fn to_str(&self) -> &str {
+        match self {
+            ObsScaleSampling::Point => "point",
+            ObsScaleSampling::Bilinear => "bilinear",
+            ObsScaleSampling::Bicubic => "bicubic",
+            ObsScaleSampling::Lanczos => "lanczos",
+            ObsScaleSampling::Area => "area",
+        }
+    }
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Scales a source to a fixed resolution or aspect ratio.
+    struct ScalingFilter("scale_filter") for ObsSourceRef {
+        #[obs_property(type_t = "enum_string")]
+        /// The scaling algorithm.
+        sampling: ObsScaleSampling,
+
+        #[obs_property(type_t = "string", settings_key = "resolution")]
+        /// The resolution (`1280x720`) or aspect ratio (`16:9`) to scale to. Use
+        /// `set_resolution` instead.
+        resolution_raw: String,
+
+        #[obs_property(type_t = "bool")]
+        /// Reduces distortion when scaling to a different aspect ratio.
+        undistort: bool,
+    }
+);
+
+#[obs_object_impl]
+impl ScalingFilter {
+    pub fn set_resolution(self, width: u32, height: u32) -> Self {
+        self.set_resolution_raw(format!("{}x{}", width, height))
+    }
+}
+
+impl ObsFilterBuilder for ScalingFilterBuilder {}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Sharpens a source.
+    struct SharpenFilter("sharpness_filter_v2") for ObsSourceRef {
+        #[obs_property(type_t = "double")]
+        /// Ranging from 0 to 1.
+        sharpness: f64,
+    }
+);
+
+impl ObsFilterBuilder for SharpenFilterBuilder {}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Applies a color lookup table (`.cube` or `.png` LUT) to a source.
+    struct LutFilter("clut_filter") for ObsSourceRef {
+        #[obs_property(type_t = "string", settings_key = "image_path")]
+        /// The path of the LUT. Use `set_lut` instead.
+        image_path_raw: String,
+
+        #[obs_property(type_t = "double", settings_key = "clut_amount")]
+        /// How strong the LUT is applied, ranging from 0 to 1.
+        amount: f64,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the alpha channel should not be changed by the LUT.
+        passthrough_alpha: bool,
+    }
+);
+
+#[obs_object_impl]
+impl LutFilter {
+    pub fn set_lut(self, path: &Path) -> Self {
+        self.set_image_path_raw(path.to_string_lossy().as_ref())
+    }
+}
+
+impl ObsFilterBuilder for LutFilterBuilder {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// How the image of the image mask filter is applied.
+pub enum ObsMaskType {
+    /// Uses the color channel of the image as alpha
+    AlphaMaskColorChannel,
+    /// Uses the alpha channel of the image as alpha
+    AlphaMaskAlphaChannel,
+    BlendMultiply,
+    BlendAddition,
+    BlendSubtraction,
+}
+
+impl StringEnum for ObsMaskType {
+    fn to_str(&self) -> &str {
+        match self {
+            ObsMaskType::AlphaMaskColorChannel => "mask_color_filter.effect",
+            ObsMaskType::AlphaMaskAlphaChannel => "mask_alpha_filter.effect",
+            ObsMaskType::BlendMultiply => "blend_mul_filter.effect",
+            ObsMaskType::BlendAddition => "blend_add_filter.effect",
+            ObsMaskType::BlendSubtraction => "blend_sub_filter.effect",
+        }
+    }
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Masks or blends a source with an image.
+    struct ImageMaskFilter("mask_filter_v2") for ObsSourceRef {
+        #[obs_property(type_t = "enum_string", settings_key = "type")]
+        /// How the image is applied.
+        mask_type: ObsMaskType,
+
+        #[obs_property(type_t = "string", settings_key = "image_path")]
+        /// The path of the image. Use `set_image` instead.
+        image_path_raw: String,
+
+        #[obs_property(type_t = "int", settings_key = "color")]
+        /// The color the image is multiplied with in the `0xAABBGGRR` format. Use `set_color` instead.
+        color_raw: i64,
+
+        #[obs_property(type_t = "double")]
+        /// Ranging from 0 to 1.
+        opacity: f64,
+
+        #[obs_property(type_t = "bool")]
+        /// Stretches the image to the size of the source.
+        stretch: bool,
+    }
+);
+
+#[obs_object_impl]
+impl ImageMaskFilter {
+    pub fn set_image(self, path: &Path) -> Self {
+        self.set_image_path_raw(path.to_string_lossy().as_ref())
+    }
+
+    pub fn set_color(self, color: ObsColor) -> Self {
+        self.set_color_raw(color.to_abgr())
+    }
+}
+
+impl ObsFilterBuilder for ImageMaskFilterBuilder {}
@@ -1,6 +1,9 @@
 //! A library for creating OBS sources without having to figure out what properties are used by sources.
 //! Example usage (for window capture only on windows):
 //! ```no_run
+//! # // Window capture only exists on Windows
+//! # #[cfg(windows)]
+//! # {
 //! use libobs_window_helper::WindowSearchMode;
 //! use libobs_wrapper::{context::ObsContext, sources::ObsSourceBuilder, utils::{OutputInfo, StartupInfo}};
 //! use libobs_sources::windows::WindowCaptureSourceBuilder;
@@ -26,15 +29,25 @@
 //! // Obs Output is created from `ObsContext`
 //! .add_to_output(output, 0)
 //! .unwrap();
+//! # }
 //! `````
 
-#[cfg(not(windows))]
-compiler_error!("libobs-sources can only be used in windows");
+#[cfg(not(any(windows, target_os = "linux")))]
+compile_error!("libobs-sources can only be used on windows and linux");
 
+#[cfg(target_os = "linux")]
+pub mod linux;
+pub mod sources;
+#[cfg(windows)]
 pub mod windows;
 
 pub mod encoders;
+pub mod filters;
 mod macro_helper;
 pub mod output;
+mod property_helper;
 
-pub use libobs_wrapper::{data::ObsObjectUpdater, sources::ObsSourceBuilder};
+pub use libobs_wrapper::{
+    data::ObsObjectUpdater,
+    sources::{ObsFilterBuilder, ObsSourceBuilder},
+};
@@ -0,0 +1,11 @@
+mod pipewire;
+pub use pipewire::*;
+
+mod xshm;
+pub use xshm::*;
+
+mod xcomposite;
+pub use xcomposite::*;
+
+mod v4l2;
+pub use v4l2::*;
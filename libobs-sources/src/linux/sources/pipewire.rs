@@ -0,0 +1,43 @@
+//! Screen and window capture through the xdg-desktop-portal and PipeWire.
+//!
+//! This is the only way to capture the screen on Wayland. Which monitor or window is captured
+//! is chosen by the user in a dialog of the desktop environment when the source is created.
+//! The choice is stored in a restore token, so the dialog is only shown again if the token
+//! is rejected by the portal.
+use libobs_wrapper::sources::{ObsSourceBuilder, ObsSourceRef};
+
+use crate::macro_helper::define_object_manager;
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Captures a monitor using the screencast portal of the desktop environment.
+    struct PipeWireScreenCaptureSource("pipewire-screen-capture-source") for ObsSourceRef {
+        #[obs_property(type_t = "bool", settings_key = "ShowCursor")]
+        /// Sets whether the cursor should be captured.
+        show_cursor: bool,
+
+        #[obs_property(type_t = "string", settings_key = "RestoreToken")]
+        /// The token of a previous session, so the user does not have to choose the monitor
+        /// again. Read it from the settings of a previously created source.
+        restore_token: String,
+    }
+);
+
+impl ObsSourceBuilder for PipeWireScreenCaptureSourceBuilder {}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Captures a window using the screencast portal of the desktop environment.
+    struct PipeWireWindowCaptureSource("pipewire-window-capture-source") for ObsSourceRef {
+        #[obs_property(type_t = "bool", settings_key = "ShowCursor")]
+        /// Sets whether the cursor should be captured.
+        show_cursor: bool,
+
+        #[obs_property(type_t = "string", settings_key = "RestoreToken")]
+        /// The token of a previous session, so the user does not have to choose the window
+        /// again. Read it from the settings of a previously created source.
+        restore_token: String,
+    }
+);
+
+impl ObsSourceBuilder for PipeWireWindowCaptureSourceBuilder {}
@@ -0,0 +1,124 @@
+//! Video capture devices (webcams, capture cards) using Video4Linux2.
+use libobs_source_macro::obs_object_impl;
+use libobs_wrapper::{
+    data::properties::types::ObsListItemValue,
+    runtime::ObsRuntime,
+    sources::{ObsSourceBuilder, ObsSourceRef},
+    utils::ObsError,
+};
+use num_derive::{FromPrimitive, ToPrimitive};
+
+use crate::{macro_helper::define_object_manager, property_helper::get_list_items};
+
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// Describes the color range of the frames of a V4L2 device.
+/// Used in `V4l2InputSourceBuilder`
+pub enum ObsV4l2ColorRange {
+    /// Uses the color range reported by the device
+    Default = 0,
+    /// Limited range (16-235)
+    Partial = 1,
+    /// Full range (0-255)
+    Full = 2,
+}
+
+/// A device that can be captured by `V4l2InputSourceBuilder`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct V4l2Device {
+    /// The path of the device, e.g. `/dev/video0`
+    pub path: String,
+    /// The name of the device, as shown by OBS
+    pub name: String,
+}
+
+/// Packs two values into the format the plugin stores resolutions and frame rates in.
+fn pack_tuple(a: u32, b: u32) -> i64 {
+    ((a as i64) << 16) | (b as i64 & 0xffff)
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Provides a easy to use builder for the Video4Linux2 capture source (`v4l2_input`).
+    ///
+    /// Use `V4l2InputSourceBuilder::get_devices` to get the devices that can be captured.
+    struct V4l2InputSource("v4l2_input") for ObsSourceRef {
+        #[obs_property(type_t = "string")]
+        /// The path of the device to capture. Use `set_device` instead.
+        device_id: String,
+
+        #[obs_property(type_t = "int")]
+        /// The input of the device to capture, for devices with multiple inputs.
+        input: i64,
+
+        #[obs_property(type_t = "int")]
+        /// The fourcc code of the pixel format, e.g. `YUYV` or `MJPG`. Use `set_pixel_format` instead.
+        pixelformat: i64,
+
+        #[obs_property(type_t = "int", settings_key = "resolution")]
+        /// The packed resolution. Use `set_resolution` instead.
+        resolution_raw: i64,
+
+        #[obs_property(type_t = "int", settings_key = "framerate")]
+        /// The packed frame interval. Use `set_frame_rate` instead.
+        framerate_raw: i64,
+
+        #[obs_property(type_t = "enum")]
+        /// The color range of the frames.
+        color_range: ObsV4l2ColorRange,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether frames should be buffered. Disable this to reduce latency.
+        buffering: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Restarts the device if no frame was received for `timeout_frames` frames.
+        auto_reset: bool,
+
+        #[obs_property(type_t = "int")]
+        /// The number of frames after which the device is restarted, if `auto_reset` is set.
+        timeout_frames: i64,
+    }
+);
+
+#[obs_object_impl]
+impl V4l2InputSource {
+    pub fn set_device(self, device: &V4l2Device) -> Self {
+        self.set_device_id(device.path.as_str())
+    }
+
+    /// Sets the pixel format using its fourcc code, e.g. `*b"MJPG"`.
+    pub fn set_pixel_format(self, fourcc: [u8; 4]) -> Self {
+        self.set_pixelformat(u32::from_le_bytes(fourcc) as i64)
+    }
+
+    pub fn set_resolution(self, width: u32, height: u32) -> Self {
+        self.set_resolution_raw(pack_tuple(width, height))
+    }
+
+    /// Sets the frame rate as fraction, e.g. `(30, 1)` or `(30000, 1001)`.
+    pub fn set_frame_rate(self, numerator: u32, denominator: u32) -> Self {
+        // The plugin stores the frame interval, which is the inverse of the frame rate
+        self.set_framerate_raw(pack_tuple(denominator, numerator))
+    }
+}
+
+impl V4l2InputSourceBuilder {
+    /// Gets all V4L2 devices that support capturing video.
+    pub fn get_devices(runtime: &ObsRuntime) -> Result<Vec<V4l2Device>, ObsError> {
+        let devices = get_list_items("v4l2_input", "device_id", runtime)?
+            .into_iter()
+            .filter_map(|item| match item.value() {
+                ObsListItemValue::String(path) => Some(V4l2Device {
+                    path: path.clone(),
+                    name: item.name().clone(),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Ok(devices)
+    }
+}
+
+impl ObsSourceBuilder for V4l2InputSourceBuilder {}
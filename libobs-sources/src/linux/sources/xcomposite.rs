@@ -0,0 +1,95 @@
+//! Window capture for X11 using the XComposite extension.
+use libobs_source_macro::obs_object_impl;
+use libobs_wrapper::{
+    data::properties::types::ObsListItemValue,
+    runtime::ObsRuntime,
+    sources::{ObsSourceBuilder, ObsSourceRef},
+    utils::ObsError,
+};
+
+use crate::{macro_helper::define_object_manager, property_helper::get_list_items};
+
+/// A window that can be captured by `XCompositeWindowCaptureSourceBuilder`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XCompositeWindow {
+    /// The id that is stored in the `capture_window` setting. It contains the X window id,
+    /// the title and the class of the window, so the window can be found again after it was
+    /// closed.
+    pub id: String,
+    /// The title of the window, as shown by OBS
+    pub name: String,
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Provides a easy to use builder for the X11 window capture source (`xcomposite_input`).
+    /// Does not work on Wayland, use `PipeWireWindowCaptureSourceBuilder` there.
+    struct XCompositeWindowCaptureSource("xcomposite_input") for ObsSourceRef {
+        #[obs_property(type_t = "string", settings_key = "capture_window")]
+        /// The window to capture. Use `set_window` instead.
+        window_raw: String,
+
+        #[obs_property(type_t = "bool")]
+        /// Sets whether the cursor should be captured.
+        show_cursor: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the border of the window should be captured as well.
+        include_border: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Ignores the alpha channel of the window.
+        exclude_alpha: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Swaps the red and blue channels, needed for some drivers.
+        swap_redblue: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Locks the X server while capturing, which may fix flickering.
+        lock_x: bool,
+
+        #[obs_property(type_t = "int")]
+        /// Pixels to crop from the top of the window.
+        cut_top: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Pixels to crop from the left of the window.
+        cut_left: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Pixels to crop from the right of the window.
+        cut_right: i64,
+
+        #[obs_property(type_t = "int", settings_key = "cut_bot")]
+        /// Pixels to crop from the bottom of the window.
+        cut_bottom: i64,
+    }
+);
+
+#[obs_object_impl]
+impl XCompositeWindowCaptureSource {
+    pub fn set_window(self, window: &XCompositeWindow) -> Self {
+        self.set_window_raw(window.id.as_str())
+    }
+}
+
+impl XCompositeWindowCaptureSourceBuilder {
+    /// Gets all windows that can be captured by this source.
+    pub fn get_windows(runtime: &ObsRuntime) -> Result<Vec<XCompositeWindow>, ObsError> {
+        let windows = get_list_items("xcomposite_input", "capture_window", runtime)?
+            .into_iter()
+            .filter_map(|item| match item.value() {
+                ObsListItemValue::String(id) if !id.is_empty() => Some(XCompositeWindow {
+                    id: id.clone(),
+                    name: item.name().clone(),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Ok(windows)
+    }
+}
+
+impl ObsSourceBuilder for XCompositeWindowCaptureSourceBuilder {}
@@ -0,0 +1,85 @@
+//! Screen capture for X11 using the MIT-SHM extension.
+use libobs_source_macro::obs_object_impl;
+use libobs_wrapper::{
+    data::properties::types::ObsListItemValue,
+    runtime::ObsRuntime,
+    sources::{ObsSourceBuilder, ObsSourceRef},
+    utils::ObsError,
+};
+
+use crate::{macro_helper::define_object_manager, property_helper::get_list_items};
+
+/// A screen that can be captured by `XshmScreenCaptureSourceBuilder`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XshmScreen {
+    /// The index that is stored in the `screen` setting
+    pub index: i64,
+    /// The name of the screen including its resolution and position, as shown by OBS
+    pub name: String,
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Provides a easy to use builder for the X11 screen capture source (`xshm_input`).
+    /// Does not work on Wayland, use `PipeWireScreenCaptureSourceBuilder` there.
+    struct XshmScreenCaptureSource("xshm_input") for ObsSourceRef {
+        #[obs_property(type_t = "int", settings_key = "screen")]
+        /// The index of the screen to capture. Use `set_screen` instead.
+        screen_raw: i64,
+
+        #[obs_property(type_t = "bool")]
+        /// Sets whether the cursor should be captured.
+        show_cursor: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether `server` should be used instead of the default X server.
+        advanced: bool,
+
+        #[obs_property(type_t = "string")]
+        /// The X server to connect to, e.g. `:1`. Only used if `advanced` is set.
+        server: String,
+
+        #[obs_property(type_t = "int")]
+        /// Pixels to crop from the top of the screen.
+        cut_top: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Pixels to crop from the left of the screen.
+        cut_left: i64,
+
+        #[obs_property(type_t = "int")]
+        /// Pixels to crop from the right of the screen.
+        cut_right: i64,
+
+        #[obs_property(type_t = "int", settings_key = "cut_bot")]
+        /// Pixels to crop from the bottom of the screen.
+        cut_bottom: i64,
+    }
+);
+
+#[obs_object_impl]
+impl XshmScreenCaptureSource {
+    pub fn set_screen(self, screen: &XshmScreen) -> Self {
+        self.set_screen_raw(screen.index)
+    }
+}
+
+impl XshmScreenCaptureSourceBuilder {
+    /// Gets all screens of the default X server.
+    pub fn get_screens(runtime: &ObsRuntime) -> Result<Vec<XshmScreen>, ObsError> {
+        let screens = get_list_items("xshm_input", "screen", runtime)?
+            .into_iter()
+            .filter_map(|item| match item.value() {
+                ObsListItemValue::Int(index) => Some(XshmScreen {
+                    index: *index,
+                    name: item.name().clone(),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Ok(screens)
+    }
+}
+
+impl ObsSourceBuilder for XshmScreenCaptureSourceBuilder {}
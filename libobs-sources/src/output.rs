@@ -1,4 +1,11 @@
 use libobs_source_macro::obs_object_builder;
+#[cfg(target_os = "linux")]
+use libobs_wrapper::data::ObsObjectBuilder;
+use libobs_wrapper::{
+    run_with_obs,
+    runtime::ObsRuntime,
+    utils::{ObsError, ObsString},
+};
 
 macro_rules! new_output_builder {
     ($builder:ident, $output_type:literal) => {
@@ -57,3 +64,40 @@ macro_rules! new_output_builder {
 
 new_output_builder!(FFmpegMuxerOutput, "ffmpeg_muxer");
 new_output_builder!(ReplayBufferOutput, "replay_buffer");
+
+/// The id of the virtual camera output on Windows and Linux.
+const VIRTUAL_CAM_ID: &str = "virtualcam_output";
+
+#[obs_object_builder("virtualcam_output")]
+/// Sends the program output (or the video of the view it is connected to) to a virtual webcam,
+/// so it can be used in other applications.
+///
+/// On Windows this requires the OBS Virtual Camera driver, on Linux the `v4l2loopback` kernel
+/// module. Use `VirtualCamOutputBuilder::is_available` to check whether it is installed.
+/// The virtual camera outputs raw video, so no encoders have to be set before starting it.
+pub struct VirtualCamOutputBuilder {}
+
+impl VirtualCamOutputBuilder {
+    /// Checks whether the virtual camera driver is installed.
+    ///
+    /// The plugins only register the virtual camera output if the driver (Windows) or the
+    /// `v4l2loopback` module (Linux) is available, so this checks if the output type exists.
+    pub fn is_available(runtime: &ObsRuntime) -> Result<bool, ObsError> {
+        let id = ObsString::new(VIRTUAL_CAM_ID);
+        let id_ptr = id.as_ptr();
+
+        let flags = run_with_obs!(runtime, (id_ptr), move || unsafe {
+            libobs::obs_get_output_flags(id_ptr)
+        })?;
+
+        Ok(flags & libobs::OBS_OUTPUT_VIDEO != 0)
+    }
+
+    /// Sets the `v4l2loopback` device to use, e.g. `/dev/video10`. If not set, the first
+    /// loopback device that is not in use is chosen.
+    #[cfg(target_os = "linux")]
+    pub fn set_device<T: Into<ObsString> + Sync + Send>(mut self, device: T) -> Self {
+        self.get_settings_updater().set_string_ref("device", device);
+        self
+    }
+}
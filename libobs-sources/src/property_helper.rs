@@ -0,0 +1,30 @@
+use libobs_wrapper::{
+    data::properties::{types::ObsListItem, ObsProperty, ObsPropertyObject},
+    runtime::ObsRuntime,
+    sources::ObsSourceRef,
+    utils::ObsError,
+};
+
+/// Returns the enabled items of the list property `property` of the given source type.
+///
+/// Sources like audio or video capture fill these lists with the available devices when
+/// their properties are created, so this can be used to enumerate devices without creating
+/// a source first.
+pub(crate) fn get_list_items(
+    source_id: &str,
+    property: &str,
+    runtime: &ObsRuntime,
+) -> Result<Vec<ObsListItem>, ObsError> {
+    let properties = ObsSourceRef::get_properties_by_id(source_id, runtime)?;
+    let items = match properties.get(property) {
+        Some(ObsProperty::List(list)) => list
+            .items()
+            .iter()
+            .filter(|item| !*item.disabled())
+            .cloned()
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Ok(items)
+}
@@ -0,0 +1,213 @@
+//! Audio input and output capture sources of the platform's audio system
+//! (WASAPI on Windows, PulseAudio or ALSA on Linux).
+//!
+//! Use `list_devices` of the builders to get the devices that can be captured.
+use libobs_source_macro::obs_object_impl;
+use libobs_wrapper::{
+    data::properties::types::ObsListItemValue,
+    runtime::ObsRuntime,
+    sources::{ObsSourceBuilder, ObsSourceRef},
+    utils::ObsError,
+};
+
+use crate::{macro_helper::define_object_manager, property_helper::get_list_items};
+
+/// An audio device as listed by the `device_id` property of an audio capture source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AudioDevice {
+    /// The id that is stored in the `device_id` setting
+    pub id: String,
+    /// The name of the device as shown by OBS
+    pub name: String,
+    /// Whether this entry follows the default device of the system
+    pub is_default: bool,
+}
+
+/// Reads the devices of the `device_id` list property of the given source type.
+fn list_devices(source_id: &str, runtime: &ObsRuntime) -> Result<Vec<AudioDevice>, ObsError> {
+    let devices = get_list_items(source_id, "device_id", runtime)?
+        .into_iter()
+        .filter_map(|item| match item.value() {
+            // ALSA lists an entry to enter a custom PCM device, which is not a device
+            ObsListItemValue::String(id) if id != "__custom__" => Some(AudioDevice {
+                is_default: id == "default",
+                id: id.clone(),
+                name: item.name().clone(),
+            }),
+            _ => None,
+        })
+        .collect();
+
+    Ok(devices)
+}
+
+#[cfg(windows)]
+define_object_manager!(
+    #[derive(Debug)]
+    /// Captures a microphone or another audio input device using WASAPI.
+    struct WasapiInputCaptureSource("wasapi_input_capture") for ObsSourceRef {
+        #[obs_property(type_t = "string")]
+        /// The id of the device to capture. Use `set_device` instead.
+        device_id: String,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the timestamps of the device should be used instead of the time of arrival.
+        use_device_timing: bool,
+    }
+);
+
+#[cfg(windows)]
+#[obs_object_impl]
+impl WasapiInputCaptureSource {
+    pub fn set_device(self, device: &AudioDevice) -> Self {
+        self.set_device_id(device.id.as_str())
+    }
+}
+
+#[cfg(windows)]
+impl WasapiInputCaptureSourceBuilder {
+    /// Returns the audio input devices that can be captured.
+    pub fn list_devices(runtime: &ObsRuntime) -> Result<Vec<AudioDevice>, ObsError> {
+        list_devices("wasapi_input_capture", runtime)
+    }
+}
+
+#[cfg(windows)]
+impl ObsSourceBuilder for WasapiInputCaptureSourceBuilder {}
+
+#[cfg(windows)]
+define_object_manager!(
+    #[derive(Debug)]
+    /// Captures the audio that is played on an output device (desktop audio) using WASAPI.
+    struct WasapiOutputCaptureSource("wasapi_output_capture") for ObsSourceRef {
+        #[obs_property(type_t = "string")]
+        /// The id of the device to capture. Use `set_device` instead.
+        device_id: String,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the timestamps of the device should be used instead of the time of arrival.
+        use_device_timing: bool,
+    }
+);
+
+#[cfg(windows)]
+#[obs_object_impl]
+impl WasapiOutputCaptureSource {
+    pub fn set_device(self, device: &AudioDevice) -> Self {
+        self.set_device_id(device.id.as_str())
+    }
+}
+
+#[cfg(windows)]
+impl WasapiOutputCaptureSourceBuilder {
+    /// Returns the audio output devices that can be captured.
+    pub fn list_devices(runtime: &ObsRuntime) -> Result<Vec<AudioDevice>, ObsError> {
+        list_devices("wasapi_output_capture", runtime)
+    }
+}
+
+#[cfg(windows)]
+impl ObsSourceBuilder for WasapiOutputCaptureSourceBuilder {}
+
+#[cfg(target_os = "linux")]
+define_object_manager!(
+    #[derive(Debug)]
+    /// Captures a microphone or another audio input device using PulseAudio.
+    struct PulseInputCaptureSource("pulse_input_capture") for ObsSourceRef {
+        #[obs_property(type_t = "string")]
+        /// The id of the device to capture. Use `set_device` instead.
+        device_id: String,
+    }
+);
+
+#[cfg(target_os = "linux")]
+#[obs_object_impl]
+impl PulseInputCaptureSource {
+    pub fn set_device(self, device: &AudioDevice) -> Self {
+        self.set_device_id(device.id.as_str())
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl PulseInputCaptureSourceBuilder {
+    /// Returns the audio input devices that can be captured.
+    pub fn list_devices(runtime: &ObsRuntime) -> Result<Vec<AudioDevice>, ObsError> {
+        list_devices("pulse_input_capture", runtime)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl ObsSourceBuilder for PulseInputCaptureSourceBuilder {}
+
+#[cfg(target_os = "linux")]
+define_object_manager!(
+    #[derive(Debug)]
+    /// Captures the audio that is played on an output device (desktop audio) using PulseAudio.
+    struct PulseOutputCaptureSource("pulse_output_capture") for ObsSourceRef {
+        #[obs_property(type_t = "string")]
+        /// The id of the device to capture. Use `set_device` instead.
+        device_id: String,
+    }
+);
+
+#[cfg(target_os = "linux")]
+#[obs_object_impl]
+impl PulseOutputCaptureSource {
+    pub fn set_device(self, device: &AudioDevice) -> Self {
+        self.set_device_id(device.id.as_str())
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl PulseOutputCaptureSourceBuilder {
+    /// Returns the audio output devices that can be captured.
+    pub fn list_devices(runtime: &ObsRuntime) -> Result<Vec<AudioDevice>, ObsError> {
+        list_devices("pulse_output_capture", runtime)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl ObsSourceBuilder for PulseOutputCaptureSourceBuilder {}
+
+#[cfg(target_os = "linux")]
+define_object_manager!(
+    #[derive(Debug)]
+    /// Captures an audio input device using ALSA.
+    struct AlsaInputCaptureSource("alsa_input_capture") for ObsSourceRef {
+        #[obs_property(type_t = "string")]
+        /// The id of the device to capture. Use `set_device` or `set_custom_pcm` instead.
+        device_id: String,
+
+        #[obs_property(type_t = "string", settings_key = "custom_pcm")]
+        /// The custom PCM device to capture, only used if `device_id` is `__custom__`.
+        custom_pcm_raw: String,
+
+        #[obs_property(type_t = "int")]
+        /// The sample rate to capture with.
+        rate: i64,
+    }
+);
+
+#[cfg(target_os = "linux")]
+#[obs_object_impl]
+impl AlsaInputCaptureSource {
+    pub fn set_device(self, device: &AudioDevice) -> Self {
+        self.set_device_id(device.id.as_str())
+    }
+
+    /// Captures a PCM device that is not listed, e.g. `hw:1,0`.
+    pub fn set_custom_pcm(self, pcm: &str) -> Self {
+        self.set_device_id("__custom__").set_custom_pcm_raw(pcm)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl AlsaInputCaptureSourceBuilder {
+    /// Returns the audio input devices that can be captured.
+    pub fn list_devices(runtime: &ObsRuntime) -> Result<Vec<AudioDevice>, ObsError> {
+        list_devices("alsa_input_capture", runtime)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl ObsSourceBuilder for AlsaInputCaptureSourceBuilder {}
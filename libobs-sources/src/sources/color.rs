@@ -0,0 +1,75 @@
+//! Color source (`color_source_v3`), which fills an area with a single color.
+use libobs_source_macro::obs_object_impl;
+use libobs_wrapper::sources::{ObsSourceBuilder, ObsSourceRef};
+
+use crate::macro_helper::define_object_manager;
+
+/// A color with an alpha channel, as used by color and text sources.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObsColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl ObsColor {
+    pub const WHITE: ObsColor = ObsColor::rgb(255, 255, 255);
+    pub const BLACK: ObsColor = ObsColor::rgb(0, 0, 0);
+    pub const TRANSPARENT: ObsColor = ObsColor::rgba(0, 0, 0, 0);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::rgba(r, g, b, 255)
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Converts a color in the `0xRRGGBB` format, e.g. `ObsColor::from_hex(0xff8800)`.
+    pub const fn from_hex(rgb: u32) -> Self {
+        Self::rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+    }
+
+    /// Returns the color in the `0xAABBGGRR` format OBS stores colors in.
+    pub const fn to_abgr(&self) -> i64 {
+        ((self.a as i64) << 24) | ((self.b as i64) << 16) | ((self.g as i64) << 8) | self.r as i64
+    }
+
+    /// Reads a color in the `0xAABBGGRR` format OBS stores colors in.
+    pub const fn from_abgr(abgr: i64) -> Self {
+        Self::rgba(
+            abgr as u8,
+            (abgr >> 8) as u8,
+            (abgr >> 16) as u8,
+            (abgr >> 24) as u8,
+        )
+    }
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// A source that fills an area of the given size with a single color.
+    struct ColorSource("color_source_v3") for ObsSourceRef {
+        #[obs_property(type_t = "int", settings_key = "color")]
+        /// The color in the `0xAABBGGRR` format. Use `set_color` instead.
+        color_raw: i64,
+
+        #[obs_property(type_t = "int")]
+        /// The width of the source in pixels. Defaults to the canvas width.
+        width: i64,
+
+        #[obs_property(type_t = "int")]
+        /// The height of the source in pixels. Defaults to the canvas height.
+        height: i64,
+    }
+);
+
+#[obs_object_impl]
+impl ColorSource {
+    pub fn set_color(self, color: ObsColor) -> Self {
+        self.set_color_raw(color.to_abgr())
+    }
+}
+
+impl ObsSourceBuilder for ColorSourceBuilder {}
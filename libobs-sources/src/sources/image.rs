@@ -0,0 +1,36 @@
+//! Image source (`image_source`), which shows an image file.
+use std::path::Path;
+
+use libobs_source_macro::obs_object_impl;
+use libobs_wrapper::sources::{ObsSourceBuilder, ObsSourceRef};
+
+use crate::macro_helper::define_object_manager;
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// A source that shows an image file (e.g. png, jpg, gif or webp).
+    /// Animated gifs are played in a loop.
+    struct ImageSource("image_source") for ObsSourceRef {
+        #[obs_property(type_t = "string")]
+        /// The path of the image file. Use `set_file_path` to set it from a `Path`.
+        file: String,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the image should be unloaded from memory when the source is not shown.
+        unload: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the alpha channel should be applied in linear space.
+        linear_alpha: bool,
+    }
+);
+
+#[obs_object_impl]
+impl ImageSource {
+    /// Shows the given image file.
+    pub fn set_file_path(self, path: &Path) -> Self {
+        self.set_file(path.to_string_lossy().as_ref())
+    }
+}
+
+impl ObsSourceBuilder for ImageSourceBuilder {}
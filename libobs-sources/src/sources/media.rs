@@ -0,0 +1,86 @@
+//! Media source (`ffmpeg_source`), which plays local video and audio files or network streams.
+//!
+//! The playback can be controlled with the `ObsMediaControls` trait of the created source.
+use std::path::Path;
+
+use libobs_source_macro::obs_object_impl;
+use libobs_wrapper::sources::{ObsSourceBuilder, ObsSourceRef};
+
+use crate::macro_helper::define_object_manager;
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// A source that plays a local media file or a network stream (e.g. `rtmp://`, `srt://`
+    /// or `http://`) using ffmpeg.
+    ///
+    /// Use `set_file` or `set_url` to set the media that should be played.
+    struct MediaSource("ffmpeg_source") for ObsSourceRef {
+        #[obs_property(type_t = "bool")]
+        /// Whether `local_file` or `input` should be played.
+        is_local_file: bool,
+
+        #[obs_property(type_t = "string")]
+        /// The path of the local file to play. Only used if `is_local_file` is set.
+        local_file: String,
+
+        #[obs_property(type_t = "string")]
+        /// The url of the network stream to play. Only used if `is_local_file` is not set.
+        input: String,
+
+        #[obs_property(type_t = "string")]
+        /// The ffmpeg input format of the network stream. Leave empty to detect it.
+        input_format: String,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the media should start again when it ended.
+        looping: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the playback should restart when the source becomes active.
+        restart_on_activate: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the media should be decoded on the GPU if possible.
+        hw_decode: bool,
+
+        #[obs_property(type_t = "int")]
+        /// The playback speed in percent, 100 being the normal speed.
+        speed_percent: i64,
+
+        #[obs_property(type_t = "int")]
+        /// The size of the network buffer in MB.
+        buffering_mb: i64,
+
+        #[obs_property(type_t = "int", settings_key = "reconnect_delay_sec")]
+        /// The delay in seconds before reconnecting to the network stream.
+        reconnect_delay: i64,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the file should be closed when the source is not active.
+        close_when_inactive: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the last frame should be cleared when the playback ended.
+        clear_on_media_end: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the network stream can be seeked.
+        seekable: bool,
+    }
+);
+
+#[obs_object_impl]
+impl MediaSource {
+    /// Plays the given local file.
+    pub fn set_file(self, path: &Path) -> Self {
+        self.set_is_local_file(true)
+            .set_local_file(path.to_string_lossy().as_ref())
+    }
+
+    /// Plays the network stream at the given url.
+    pub fn set_url(self, url: &str) -> Self {
+        self.set_is_local_file(false).set_input(url)
+    }
+}
+
+impl ObsSourceBuilder for MediaSourceBuilder {}
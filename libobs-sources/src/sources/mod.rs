@@ -0,0 +1,18 @@
+//! Sources that are available on every platform.
+mod audio;
+pub use audio::*;
+
+mod color;
+pub use color::*;
+
+mod image;
+pub use image::*;
+
+mod media;
+pub use media::*;
+
+mod text;
+pub use text::*;
+
+mod video_capture;
+pub use video_capture::*;
@@ -0,0 +1,169 @@
+//! FreeType 2 text source (`text_ft2_source_v2`), which is available on every platform.
+//!
+//! On Windows, the GDI+ text source (`TextGdiplusSourceBuilder`) offers more options.
+use std::path::Path;
+
+use libobs_source_macro::obs_object_impl;
+use libobs_wrapper::{
+    data::{ObsData, ObsObjectBuilder, ObsObjectUpdater},
+    runtime::ObsRuntime,
+    sources::{ObsSourceBuilder, ObsSourceRef},
+    utils::ObsError,
+};
+
+use crate::macro_helper::define_object_manager;
+
+use super::ObsColor;
+
+/// The font of a text source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObsFont {
+    /// The font family, e.g. `Arial`
+    pub face: String,
+    /// The style of the family, e.g. `Regular` or `Bold`
+    pub style: String,
+    pub size: u32,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikeout: bool,
+}
+
+impl ObsFont {
+    pub fn new(face: impl Into<String>, size: u32) -> Self {
+        Self {
+            face: face.into(),
+            style: "Regular".to_string(),
+            size,
+            bold: false,
+            italic: false,
+            underline: false,
+            strikeout: false,
+        }
+    }
+
+    /// Returns the `OBS_FONT_*` flags of this font.
+    pub fn flags(&self) -> i64 {
+        let mut flags = 0;
+        if self.bold {
+            flags |= libobs::OBS_FONT_BOLD;
+        }
+        if self.italic {
+            flags |= libobs::OBS_FONT_ITALIC;
+        }
+        if self.underline {
+            flags |= libobs::OBS_FONT_UNDERLINE;
+        }
+        if self.strikeout {
+            flags |= libobs::OBS_FONT_STRIKEOUT;
+        }
+
+        flags as i64
+    }
+
+    /// Creates the `font` object the text sources read the font from.
+    pub fn to_obs_data(&self, runtime: ObsRuntime) -> Result<ObsData, ObsError> {
+        let mut data = ObsData::new(runtime)?;
+        data.bulk_update()
+            .set_string("face", self.face.as_str())
+            .set_string("style", self.style.as_str())
+            .set_int("size", self.size as i64)
+            .set_int("flags", self.flags())
+            .update()?;
+
+        Ok(data)
+    }
+}
+
+impl Default for ObsFont {
+    fn default() -> Self {
+        Self::new("Arial", 36)
+    }
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// A source that renders text using FreeType 2.
+    ///
+    /// The text can either be set directly or read from a file, which is useful for
+    /// overlays that are updated by other programs.
+    struct TextFt2Source("text_ft2_source_v2") for ObsSourceRef {
+        #[obs_property(type_t = "string")]
+        /// The text to render.
+        text: String,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the text should be read from `text_file` instead.
+        from_file: bool,
+
+        #[obs_property(type_t = "string")]
+        /// The file the text is read from. The source reloads it when it changes.
+        text_file: String,
+
+        #[obs_property(type_t = "bool")]
+        /// Only show the last `log_lines` lines of the file.
+        log_mode: bool,
+
+        #[obs_property(type_t = "int")]
+        /// The number of lines shown in log mode.
+        log_lines: i64,
+
+        #[obs_property(type_t = "int", settings_key = "color1")]
+        /// The top color of the gradient in the `0xAABBGGRR` format. Use `set_color` instead.
+        color1_raw: i64,
+
+        #[obs_property(type_t = "int", settings_key = "color2")]
+        /// The bottom color of the gradient in the `0xAABBGGRR` format. Use `set_color` instead.
+        color2_raw: i64,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the text should be antialiased.
+        antialiasing: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Draws a black outline around the text.
+        outline: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Draws a black shadow below the text.
+        drop_shadow: bool,
+
+        #[obs_property(type_t = "int")]
+        /// The width at which the text is wrapped, if `word_wrap` is enabled.
+        custom_width: i64,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the text should be wrapped at `custom_width`.
+        word_wrap: bool,
+    }
+);
+
+#[obs_object_impl]
+impl TextFt2Source {
+    /// Sets the font of the text.
+    pub fn set_font(mut self, font: &ObsFont) -> Result<Self, ObsError> {
+        let font = font.to_obs_data(self.get_settings().runtime().clone())?;
+        self.get_settings_updater().set_obj_ref("font", font);
+
+        Ok(self)
+    }
+
+    /// Renders the text in a single color.
+    pub fn set_color(self, color: ObsColor) -> Self {
+        self.set_gradient(color, color)
+    }
+
+    /// Renders the text with a vertical gradient from `top` to `bottom`.
+    pub fn set_gradient(self, top: ObsColor, bottom: ObsColor) -> Self {
+        self.set_color1_raw(top.to_abgr())
+            .set_color2_raw(bottom.to_abgr())
+    }
+
+    /// Reads the text from the given file.
+    pub fn set_file(self, path: &Path) -> Self {
+        self.set_from_file(true)
+            .set_text_file(path.to_string_lossy().as_ref())
+    }
+}
+
+impl ObsSourceBuilder for TextFt2SourceBuilder {}
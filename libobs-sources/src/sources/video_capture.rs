@@ -0,0 +1,363 @@
+//! Video capture devices like webcams and capture cards, using DirectShow (`dshow_input`) on
+//! Windows and Video4Linux2 (`v4l2_input`) on Linux.
+//!
+//! The supported modes of a device are read from the properties of the source, so they
+//! match what OBS shows in its properties dialog.
+use libobs_source_macro::obs_object_impl;
+use libobs_wrapper::{
+    data::{
+        properties::{
+            types::{ObsListItem, ObsListItemValue},
+            ObsPropertiesHandle, ObsProperty, ObsPropertyObject,
+        },
+        ObsData, ObsDataChange, ObsObjectBuilder, ObsObjectUpdater,
+    },
+    enums::ObsVideoRange,
+    runtime::ObsRuntime,
+    sources::{ObsSourceBuilder, ObsSourceRef},
+    utils::ObsError,
+};
+
+use crate::{macro_helper::define_object_manager, property_helper::get_list_items};
+
+/// A video capture device as listed by the device property of the source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VideoCaptureDeviceInfo {
+    /// The id that is stored in the device setting of the source
+    pub id: String,
+    /// The name of the device as shown by OBS
+    pub name: String,
+}
+
+/// A combination of resolution, frame rate and pixel format a device supports.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VideoCaptureMode {
+    pub width: u32,
+    pub height: u32,
+    pub frame_rate: f64,
+    /// The name of the pixel format as shown by OBS, e.g. `MJPEG` or `YUYV 4:2:2`
+    pub pixel_format: String,
+    /// The settings that select this mode
+    settings: Vec<(String, ObsListItemValue)>,
+}
+
+impl VideoCaptureMode {
+    /// Returns the mode that matches the requested resolution and frame rate best.
+    ///
+    /// The resolution is matched first. If several modes have the closest resolution, the one
+    /// with the closest frame rate that is not lower than the requested one is preferred.
+    pub fn best_match(
+        modes: &[VideoCaptureMode],
+        width: u32,
+        height: u32,
+        frame_rate: f64,
+    ) -> Option<&VideoCaptureMode> {
+        modes.iter().min_by_key(|mode| {
+            let resolution_diff =
+                mode.width.abs_diff(width) as u64 + mode.height.abs_diff(height) as u64;
+            let too_slow = mode.frame_rate + 0.01 < frame_rate;
+            let frame_rate_diff = ((mode.frame_rate - frame_rate).abs() * 1000.0) as u64;
+
+            (resolution_diff, too_slow, frame_rate_diff)
+        })
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use super::*;
+
+    pub const SOURCE_ID: &str = "dshow_input";
+    pub const DEVICE_KEY: &str = "video_device_id";
+    /// The list properties that describe a mode, in the order their callbacks fill each other
+    pub const MODE_KEYS: [&str; 3] = ["resolution", "frame_interval", "video_format"];
+
+    /// `res_type` must be set to custom, otherwise the resolution is chosen by the device
+    pub const RES_TYPE_CUSTOM: i64 = 1;
+
+    /// Entries like "Highest FPS" or "Any" format, which do not describe a mode.
+    pub fn is_placeholder(key: &str, value: &ObsListItemValue) -> bool {
+        match (key, value) {
+            ("frame_interval", ObsListItemValue::Int(v)) => *v <= 0,
+            ("video_format", ObsListItemValue::Int(v)) => *v == 0,
+            _ => false,
+        }
+    }
+
+    pub fn to_mode(selected: &[(String, ObsListItem)]) -> Option<VideoCaptureMode> {
+        let [(_, resolution), (_, interval), (_, format)] = selected else {
+            return None;
+        };
+
+        let (width, height) = match resolution.value() {
+            ObsListItemValue::String(res) => {
+                let (w, h) = res.split_once('x')?;
+                (w.trim().parse().ok()?, h.trim().parse().ok()?)
+            }
+            _ => return None,
+        };
+
+        // The frame interval is stored in 100ns units
+        let frame_rate = match interval.value() {
+            ObsListItemValue::Int(interval) if *interval > 0 => 10_000_000.0 / *interval as f64,
+            _ => return None,
+        };
+
+        Some(VideoCaptureMode {
+            width,
+            height,
+            frame_rate,
+            pixel_format: format.name().clone(),
+            settings: to_settings(selected),
+        })
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::*;
+
+    pub const SOURCE_ID: &str = "v4l2_input";
+    pub const DEVICE_KEY: &str = "device_id";
+    /// The list properties that describe a mode, in the order their callbacks fill each other
+    pub const MODE_KEYS: [&str; 3] = ["pixelformat", "resolution", "framerate"];
+
+    /// The "Leave unchanged" entries, which do not describe a mode.
+    pub fn is_placeholder(_key: &str, value: &ObsListItemValue) -> bool {
+        matches!(value, ObsListItemValue::Int(-1))
+    }
+
+    pub fn to_mode(selected: &[(String, ObsListItem)]) -> Option<VideoCaptureMode> {
+        let [(_, format), (_, resolution), (_, interval)] = selected else {
+            return None;
+        };
+
+        // Resolutions and frame intervals are packed as `(a << 16) | b`
+        let (width, height) = match resolution.value() {
+            ObsListItemValue::Int(v) => ((*v >> 16) as u32, (*v & 0xffff) as u32),
+            _ => return None,
+        };
+
+        let frame_rate = match interval.value() {
+            ObsListItemValue::Int(v) if (*v >> 16) > 0 => (*v & 0xffff) as f64 / (*v >> 16) as f64,
+            _ => return None,
+        };
+
+        Some(VideoCaptureMode {
+            width,
+            height,
+            frame_rate,
+            pixel_format: format.name().clone(),
+            settings: to_settings(selected),
+        })
+    }
+}
+
+fn to_settings(selected: &[(String, ObsListItem)]) -> Vec<(String, ObsListItemValue)> {
+    selected
+        .iter()
+        .map(|(key, item)| (key.clone(), item.value().clone()))
+        .collect()
+}
+
+/// Selects every item of the list property `keys[0]` and recurses into the lists its
+/// modified callback fills, collecting a mode for every complete combination.
+fn probe_modes(
+    handle: &mut ObsPropertiesHandle,
+    settings: &mut ObsData,
+    keys: &[&str],
+    selected: &mut Vec<(String, ObsListItem)>,
+    modes: &mut Vec<VideoCaptureMode>,
+) -> Result<(), ObsError> {
+    let Some((key, rest)) = keys.split_first() else {
+        modes.extend(platform::to_mode(selected));
+        return Ok(());
+    };
+
+    let items = match handle.get(key) {
+        Some(ObsProperty::List(list)) => list.items().clone(),
+        _ => return Ok(()),
+    };
+
+    for item in items {
+        if *item.disabled() || platform::is_placeholder(key, item.value()) {
+            continue;
+        }
+
+        let change = match item.value() {
+            ObsListItemValue::String(v) => ObsDataChange::String((*key).into(), v.as_str().into()),
+            ObsListItemValue::Int(v) => ObsDataChange::Int((*key).into(), *v),
+            _ => continue,
+        };
+
+        handle.apply_change(settings, change)?;
+        selected.push((key.to_string(), item));
+        probe_modes(handle, settings, rest, selected, modes)?;
+        selected.pop();
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+define_object_manager!(
+    #[derive(Debug)]
+    /// Captures a webcam or capture card using DirectShow.
+    ///
+    /// Use `VideoCaptureDeviceBuilder::get_devices` and `VideoCaptureDeviceBuilder::get_modes`
+    /// to find the device and mode to capture.
+    struct VideoCaptureDevice("dshow_input") for ObsSourceRef {
+        #[obs_property(type_t = "string", settings_key = "video_device_id")]
+        /// The id of the device to capture. Use `set_device` instead.
+        device_id_raw: String,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the frames should be decoded on the GPU if possible.
+        hw_decode: bool,
+
+        #[obs_property(type_t = "bool", settings_key = "deactivate_when_not_showing")]
+        /// Releases the device while the source is not shown.
+        deactivate_when_hidden: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Flips the image vertically.
+        flip_vertically: bool,
+    }
+);
+
+#[cfg(target_os = "linux")]
+define_object_manager!(
+    #[derive(Debug)]
+    /// Captures a webcam or capture card using Video4Linux2.
+    ///
+    /// Use `VideoCaptureDeviceBuilder::get_devices` and `VideoCaptureDeviceBuilder::get_modes`
+    /// to find the device and mode to capture. For all options of the source, use
+    /// `V4l2InputSourceBuilder` of the `linux` module.
+    struct VideoCaptureDevice("v4l2_input") for ObsSourceRef {
+        #[obs_property(type_t = "string", settings_key = "device_id")]
+        /// The path of the device to capture. Use `set_device` instead.
+        device_id_raw: String,
+
+        #[obs_property(type_t = "bool")]
+        /// Restarts the device if no frame was received for `timeout_frames` frames.
+        auto_reset: bool,
+
+        #[obs_property(type_t = "int")]
+        /// The number of frames after which the device is restarted, if `auto_reset` is set.
+        timeout_frames: i64,
+    }
+);
+
+#[obs_object_impl]
+impl VideoCaptureDevice {
+    pub fn set_device(self, device: &VideoCaptureDeviceInfo) -> Self {
+        self.set_device_id_raw(device.id.as_str())
+    }
+
+    /// Captures the device in the given mode. Use `VideoCaptureMode::best_match` to find the
+    /// mode that is closest to the requested resolution and frame rate.
+    pub fn set_mode(mut self, mode: &VideoCaptureMode) -> Self {
+        let updater = self.get_settings_updater();
+
+        #[cfg(windows)]
+        updater.set_int_ref("res_type", platform::RES_TYPE_CUSTOM);
+
+        for (key, value) in &mode.settings {
+            match value {
+                ObsListItemValue::String(v) => updater.set_string_ref(key.as_str(), v.as_str()),
+                ObsListItemValue::Int(v) => updater.set_int_ref(key.as_str(), *v),
+                _ => {}
+            }
+        }
+
+        self
+    }
+
+    /// Whether frames should be buffered. Disable this to reduce latency.
+    pub fn set_buffering(mut self, buffering: bool) -> Self {
+        #[cfg(windows)]
+        {
+            // 0 is automatic, 1 enabled and 2 disabled
+            let value = if buffering { 1 } else { 2 };
+            self.get_settings_updater().set_int_ref("buffering", value);
+        }
+
+        #[cfg(target_os = "linux")]
+        self.get_settings_updater()
+            .set_bool_ref("buffering", buffering);
+
+        self
+    }
+
+    /// Sets the color range of the frames. `ObsVideoRange::Default` uses the range the
+    /// device reports.
+    pub fn set_color_range(mut self, range: ObsVideoRange) -> Self {
+        #[cfg(windows)]
+        {
+            let value = match range {
+                ObsVideoRange::Default => "default",
+                ObsVideoRange::Partial => "partial",
+                ObsVideoRange::Full => "full",
+            };
+            self.get_settings_updater()
+                .set_string_ref("color_range", value);
+        }
+
+        #[cfg(target_os = "linux")]
+        self.get_settings_updater()
+            .set_int_ref("color_range", range as i64);
+
+        self
+    }
+}
+
+impl VideoCaptureDeviceBuilder {
+    /// Gets all video capture devices.
+    pub fn get_devices(runtime: &ObsRuntime) -> Result<Vec<VideoCaptureDeviceInfo>, ObsError> {
+        let devices = get_list_items(platform::SOURCE_ID, platform::DEVICE_KEY, runtime)?
+            .into_iter()
+            .filter_map(|item| match item.value() {
+                ObsListItemValue::String(id) if !id.is_empty() => Some(VideoCaptureDeviceInfo {
+                    id: id.clone(),
+                    name: item.name().clone(),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Ok(devices)
+    }
+
+    /// Gets all modes the given device supports.
+    ///
+    /// This selects every combination of resolution, frame rate and pixel format in the
+    /// properties of the source, which opens the device and may take a moment.
+    pub fn get_modes(
+        device: &VideoCaptureDeviceInfo,
+        runtime: &ObsRuntime,
+    ) -> Result<Vec<VideoCaptureMode>, ObsError> {
+        let mut handle = ObsSourceRef::get_properties_handle_by_id(platform::SOURCE_ID, runtime)?;
+        let mut settings = ObsData::new(runtime.clone())?;
+
+        #[cfg(windows)]
+        settings.set_int("res_type", platform::RES_TYPE_CUSTOM)?;
+
+        handle.apply_change(
+            &mut settings,
+            ObsDataChange::String(platform::DEVICE_KEY.into(), device.id.as_str().into()),
+        )?;
+
+        let mut modes = Vec::new();
+        probe_modes(
+            &mut handle,
+            &mut settings,
+            &platform::MODE_KEYS,
+            &mut Vec::new(),
+            &mut modes,
+        )?;
+
+        Ok(modes)
+    }
+}
+
+impl ObsSourceBuilder for VideoCaptureDeviceBuilder {}
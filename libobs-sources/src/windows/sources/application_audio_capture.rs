@@ -0,0 +1,51 @@
+use libobs_source_macro::obs_object_impl;
+#[cfg(feature = "window-list")]
+use libobs_window_helper::{get_all_windows, WindowInfo, WindowSearchMode};
+use libobs_wrapper::sources::{ObsSourceBuilder, ObsSourceRef};
+
+use crate::macro_helper::define_object_manager;
+
+use super::ObsWindowPriority;
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Captures the audio of a single application (Windows 10 2004 and up).
+    ///
+    /// In contrast to desktop audio, only the audio of the process of the given window is
+    /// captured, so e.g. the audio of a game can be recorded separately from voice chat.
+    /// Use `ApplicationAudioCaptureBuilder::get_windows` to get a list of windows (feature
+    /// `window-list` needs to be enabled).
+    struct ApplicationAudioCapture("wasapi_process_output_capture") for ObsSourceRef {
+        /// Sets the window of the application to capture.
+        ///
+        /// # Arguments
+        ///
+        /// * `window` - The window of the application, represented as `ObsString`. Must be in the format of an obs window id
+        #[obs_property(type_t = "string", settings_key = "window")]
+        window_raw: String,
+
+        #[obs_property(type_t = "enum")]
+        /// Window Match Priority, used to find the application again if the window was closed.
+        priority: ObsWindowPriority,
+    }
+);
+
+#[obs_object_impl]
+#[cfg(feature = "window-list")]
+impl ApplicationAudioCapture {
+    /// Gets a list of windows whose application audio can be captured.
+    pub fn get_windows(mode: WindowSearchMode) -> anyhow::Result<Vec<WindowInfo>> {
+        get_all_windows(mode)
+    }
+
+    /// Sets the window of the application to capture.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The window of the application. A list of available windows can be retrieved using `ApplicationAudioCaptureBuilder::get_windows`
+    pub fn set_window(self, window: &WindowInfo) -> Self {
+        self.set_window_raw(window.obs_id.as_str())
+    }
+}
+
+impl ObsSourceBuilder for ApplicationAudioCaptureBuilder {}
@@ -10,4 +10,10 @@ pub use game_capture::*;
 mod monitor_capture;
 pub use monitor_capture::*;
 
+mod text_gdiplus;
+pub use text_gdiplus::*;
+
+mod application_audio_capture;
+pub use application_audio_capture::*;
+
 pub use libobs_window_helper::{WindowInfo, WindowSearchMode};
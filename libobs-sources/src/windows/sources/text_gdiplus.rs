@@ -0,0 +1,221 @@
+use std::path::Path;
+
+use libobs_source_macro::obs_object_impl;
+use libobs_wrapper::{
+    data::{ObsObjectBuilder, ObsObjectUpdater, StringEnum},
+    sources::{ObsSourceBuilder, ObsSourceRef},
+    utils::ObsError,
+};
+use num_derive::{FromPrimitive, ToPrimitive};
+
+use crate::{
+    macro_helper::define_object_manager,
+    sources::{ObsColor, ObsFont},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Horizontal alignment of the text.
+pub enum ObsTextAlign {
+    Left,
+    Center,
+    Right,
+}
+
+impl StringEnum for ObsTextAlign {
+    fn to_str(&self) -> &str {
+        match self {
+            ObsTextAlign::Left => "left",
+            ObsTextAlign::Center => "center",
+            ObsTextAlign::Right => "right",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Vertical alignment of the text.
+pub enum ObsTextVAlign {
+    Top,
+    Center,
+    Bottom,
+}
+
+impl StringEnum for ObsTextVAlign {
+    fn to_str(&self) -> &str {
+        match self {
+            ObsTextVAlign::Top => "top",
+            ObsTextVAlign::Center => "center",
+            ObsTextVAlign::Bottom => "bottom",
+        }
+    }
+}
+
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// Changes the case of the text before it is rendered.
+pub enum ObsTextTransform {
+    None = 0,
+    Uppercase = 1,
+    Lowercase = 2,
+    /// Capitalizes the first letter of every word
+    StartCase = 3,
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// A source that renders text using GDI+. Only available on Windows, use
+    /// `TextFt2SourceBuilder` for other platforms.
+    struct TextGdiplusSource("text_gdiplus_v3") for ObsSourceRef {
+        #[obs_property(type_t = "string")]
+        /// The text to render.
+        text: String,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the text should be read from `file` instead.
+        read_from_file: bool,
+
+        #[obs_property(type_t = "string")]
+        /// The file the text is read from. The source reloads it when it changes.
+        file: String,
+
+        #[obs_property(type_t = "int", settings_key = "color")]
+        /// The text color in the `0xAABBGGRR` format. Use `set_color` instead.
+        color_raw: i64,
+
+        #[obs_property(type_t = "int")]
+        /// The opacity of the text, ranging from 0 to 100.
+        opacity: i64,
+
+        #[obs_property(type_t = "bool", settings_key = "gradient")]
+        /// Whether the text should be rendered with a gradient. Use `set_gradient` instead.
+        gradient_enabled: bool,
+
+        #[obs_property(type_t = "int", settings_key = "gradient_color")]
+        /// The second color of the gradient in the `0xAABBGGRR` format. Use `set_gradient` instead.
+        gradient_color_raw: i64,
+
+        #[obs_property(type_t = "int")]
+        /// The opacity of the gradient, ranging from 0 to 100.
+        gradient_opacity: i64,
+
+        #[obs_property(type_t = "int", settings_key = "bk_color")]
+        /// The background color in the `0xAABBGGRR` format. Use `set_background` instead.
+        bk_color_raw: i64,
+
+        #[obs_property(type_t = "int")]
+        /// The opacity of the background, ranging from 0 to 100.
+        bk_opacity: i64,
+
+        #[obs_property(type_t = "enum_string")]
+        /// Horizontal alignment of the text.
+        align: ObsTextAlign,
+
+        #[obs_property(type_t = "enum_string")]
+        /// Vertical alignment of the text.
+        valign: ObsTextVAlign,
+
+        #[obs_property(type_t = "bool")]
+        /// Renders the text vertically.
+        vertical: bool,
+
+        #[obs_property(type_t = "enum")]
+        /// Changes the case of the text.
+        transform: ObsTextTransform,
+
+        #[obs_property(type_t = "bool")]
+        /// Draws an outline around the text.
+        outline: bool,
+
+        #[obs_property(type_t = "int")]
+        /// The size of the outline in pixels.
+        outline_size: i64,
+
+        #[obs_property(type_t = "int", settings_key = "outline_color")]
+        /// The outline color in the `0xAABBGGRR` format. Use `set_outline_color` instead.
+        outline_color_raw: i64,
+
+        #[obs_property(type_t = "int")]
+        /// The opacity of the outline, ranging from 0 to 100.
+        outline_opacity: i64,
+
+        #[obs_property(type_t = "bool", settings_key = "chatlog")]
+        /// Only show the last `chatlog_lines` lines of the text.
+        chatlog_mode: bool,
+
+        #[obs_property(type_t = "int")]
+        /// The number of lines shown in chatlog mode.
+        chatlog_lines: i64,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the size of the source should be fixed to `extents_cx` x `extents_cy`.
+        extents: bool,
+
+        #[obs_property(type_t = "int")]
+        /// The width of the source if `extents` is enabled.
+        extents_cx: i64,
+
+        #[obs_property(type_t = "int")]
+        /// The height of the source if `extents` is enabled.
+        extents_cy: i64,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the text should be wrapped at the width of the extents.
+        extents_wrap: bool,
+
+        #[obs_property(type_t = "bool")]
+        /// Whether the text should be antialiased.
+        antialiasing: bool,
+    }
+);
+
+/// Converts the alpha channel of a color to the 0 to 100 range of the opacity settings.
+fn opacity(color: ObsColor) -> i64 {
+    color.a as i64 * 100 / 255
+}
+
+#[obs_object_impl]
+impl TextGdiplusSource {
+    /// Sets the font of the text.
+    pub fn set_font(mut self, font: &ObsFont) -> Result<Self, ObsError> {
+        let font = font.to_obs_data(self.get_settings().runtime().clone())?;
+        self.get_settings_updater().set_obj_ref("font", font);
+
+        Ok(self)
+    }
+
+    /// Sets the text color. The alpha channel of the color is used as opacity.
+    pub fn set_color(self, color: ObsColor) -> Self {
+        self.set_color_raw(color.to_abgr())
+            .set_opacity(opacity(color))
+    }
+
+    /// Renders the text with a gradient from the text color to the given color.
+    /// `direction` is the angle of the gradient in degrees.
+    pub fn set_gradient(mut self, color: ObsColor, direction: f64) -> Self {
+        self.get_settings_updater()
+            .set_double_ref("gradient_dir", direction);
+
+        self.set_gradient_enabled(true)
+            .set_gradient_color_raw(color.to_abgr())
+            .set_gradient_opacity(opacity(color))
+    }
+
+    /// Sets the background color. The alpha channel of the color is used as opacity.
+    pub fn set_background(self, color: ObsColor) -> Self {
+        self.set_bk_color_raw(color.to_abgr())
+            .set_bk_opacity(opacity(color))
+    }
+
+    /// Sets the color of the outline. The alpha channel of the color is used as opacity.
+    pub fn set_outline_color(self, color: ObsColor) -> Self {
+        self.set_outline_color_raw(color.to_abgr())
+            .set_outline_opacity(opacity(color))
+    }
+
+    /// Reads the text from the given file.
+    pub fn set_text_file(self, path: &Path) -> Self {
+        self.set_read_from_file(true)
+            .set_file(path.to_string_lossy().as_ref())
+    }
+}
+
+impl ObsSourceBuilder for TextGdiplusSourceBuilder {}
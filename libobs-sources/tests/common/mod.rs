@@ -6,8 +6,11 @@ use anyhow::bail;
 use ffmpeg_sidecar::{ffprobe::ffprobe_path, paths::ffmpeg_path};
 #[allow(unused_imports)]
 pub use initialize::*;
+#[cfg(windows)]
 use libobs_sources::windows::WindowCaptureSourceBuilder;
+#[cfg(windows)]
 use libobs_window_helper::{WindowInfo, WindowSearchMode};
+#[cfg(windows)]
 use libobs_wrapper::unsafe_send::Sendable;
 
 #[allow(dead_code)]
@@ -157,6 +160,7 @@ pub async fn assert_motion(path: &str, min_variance: f64) {
     assert!(found, "No motion info found");
 }
 
+#[cfg(windows)]
 #[allow(dead_code)]
 pub fn find_notepad() -> Option<Sendable<WindowInfo>> {
     let windows =
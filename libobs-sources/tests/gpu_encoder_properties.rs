@@ -78,3 +78,33 @@ pub fn test_encoder_type_identification() {
         );
     }
 }
+
+/// Integration test: Test running modified callbacks on a live properties handle
+#[test]
+pub fn test_encoder_properties_handle() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let encoders = context.available_video_encoders().unwrap();
+
+    // Skip if no encoders available
+    if encoders.is_empty() {
+        eprintln!("Skipping test - no video encoders available");
+        return;
+    }
+
+    let encoder = encoders.into_iter().next().unwrap();
+    let mut handle = encoder.get_properties_handle().unwrap();
+    assert!(!handle.properties().is_empty(), "Encoder has no properties");
+
+    let settings = context.data().unwrap();
+    handle.apply_settings(&settings).unwrap();
+
+    // Re-reading without running callbacks must not change anything
+    let refresh = handle.refresh().unwrap();
+    assert!(refresh.diff.is_empty());
+    assert!(!refresh.needs_refresh);
+}
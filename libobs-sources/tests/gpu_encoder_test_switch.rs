@@ -1,3 +1,4 @@
+#![cfg(windows)]
 /// Standalone reproduction for OBS crash when switching encoders
 ///
 /// Expected result: Crash eventually occurs on NVENC
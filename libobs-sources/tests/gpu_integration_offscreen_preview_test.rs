@@ -0,0 +1,107 @@
+mod common;
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use libobs_sources::sources::{ColorSourceBuilder, ObsColor};
+use libobs_wrapper::{
+    context::ObsContext,
+    display::{
+        ObsOffscreenPreview, ObsOffscreenPreviewCreationData, ObsPreviewFrame, RenderTarget,
+    },
+    sources::ObsSourceBuilder,
+    utils::StartupInfo,
+};
+
+fn wait_for_frame(preview: &ObsOffscreenPreview) -> Arc<ObsPreviewFrame> {
+    let mut rx = preview.subscribe();
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    loop {
+        match rx.try_recv() {
+            Ok(frame) => return frame,
+            Err(_) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(10)),
+            Err(e) => panic!("No frame was rendered: {:?}", e),
+        }
+    }
+}
+
+/// Integration test: Test rendering a source and the main view without a window
+#[test]
+pub fn test_offscreen_preview() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("offscreen_preview_scene").unwrap();
+    scene.set_to_channel(0).unwrap();
+
+    let source = context
+        .source_builder::<ColorSourceBuilder, _>("offscreen_preview_color")
+        .unwrap()
+        .set_color(ObsColor::rgb(255, 0, 0))
+        .set_width(320)
+        .set_height(180)
+        .add_to_scene(&mut scene)
+        .unwrap();
+
+    let preview = context
+        .offscreen_preview(
+            ObsOffscreenPreviewCreationData::new(64, 36)
+                .set_render_target(RenderTarget::Source(&source))
+                .set_max_fps(10.0),
+        )
+        .unwrap();
+
+    let frame = wait_for_frame(&preview);
+    assert_eq!(*frame.width(), 64);
+    assert_eq!(*frame.height(), 36);
+    assert_eq!(frame.data().len(), 64 * 36 * 4);
+    assert_eq!(&frame.data()[0..4], &[255, 0, 0, 255]);
+
+    preview.set_size(32, 18).unwrap();
+    let mut frame = wait_for_frame(&preview);
+    // The frame staged before resizing may still arrive
+    if *frame.width() != 32 {
+        frame = wait_for_frame(&preview);
+    }
+    assert_eq!(*frame.width(), 32);
+    assert_eq!(frame.data().len(), 32 * 18 * 4);
+
+    // A square frame letterboxes the 16:9 source with black bars at the top and bottom
+    let letterboxed = context
+        .offscreen_preview(
+            ObsOffscreenPreviewCreationData::new(64, 64)
+                .set_render_target(RenderTarget::Source(&source)),
+        )
+        .unwrap();
+
+    let frame = wait_for_frame(&letterboxed);
+    let pixel = |x: usize, y: usize| {
+        let i = (y * 64 + x) * 4;
+        frame.data()[i..i + 4].to_vec()
+    };
+    assert_eq!(pixel(32, 0), vec![0, 0, 0, 255]);
+    assert_eq!(pixel(32, 32), vec![255, 0, 0, 255]);
+    assert_eq!(pixel(32, 63), vec![0, 0, 0, 255]);
+
+    let scene_preview = context
+        .offscreen_preview(
+            ObsOffscreenPreviewCreationData::new(64, 36)
+                .set_render_target(RenderTarget::Scene(&scene)),
+        )
+        .unwrap();
+
+    let frame = wait_for_frame(&scene_preview);
+    assert_eq!(frame.data().len(), 64 * 36 * 4);
+
+    let main_preview = context
+        .offscreen_preview(ObsOffscreenPreviewCreationData::new(64, 36))
+        .unwrap();
+
+    let frame = wait_for_frame(&main_preview);
+    assert_eq!(frame.data().len(), 64 * 36 * 4);
+}
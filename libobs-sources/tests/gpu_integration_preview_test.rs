@@ -1,3 +1,4 @@
+#![cfg(windows)]
 use std::pin::Pin;
 use std::sync::{Arc, RwLock};
 use std::time::Instant;
@@ -0,0 +1,126 @@
+#![cfg(windows)]
+use std::pin::Pin;
+
+use libobs_sources::sources::{ColorSourceBuilder, ObsColor};
+use libobs_wrapper::data::video::ObsVideoInfoBuilder;
+use libobs_wrapper::display::{ObsDisplayCreationData, ObsDisplayRef, RenderTarget};
+use libobs_wrapper::scenes::ObsSceneRef;
+use libobs_wrapper::sources::ObsSourceRef;
+use libobs_wrapper::unsafe_send::Sendable;
+use libobs_wrapper::{context::ObsContext, sources::ObsSourceBuilder, utils::StartupInfo};
+use winit::application::ApplicationHandler;
+use winit::dpi::PhysicalSize;
+use winit::event::WindowEvent;
+use winit::event_loop::ActiveEventLoop;
+use winit::platform::windows::EventLoopBuilderExtWindows;
+use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use winit::window::{Window, WindowId};
+
+struct App {
+    _window: Option<Sendable<Window>>,
+    display: Option<Pin<Box<ObsDisplayRef>>>,
+    context: ObsContext,
+    scene: ObsSceneRef,
+    source: ObsSourceRef,
+}
+
+impl ApplicationHandler for App {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        let window = event_loop
+            .create_window(
+                Window::default_attributes().with_inner_size(PhysicalSize::new(640, 480)),
+            )
+            .unwrap();
+
+        let size = window.inner_size();
+        let hwnd = window.window_handle().unwrap().as_raw();
+        let hwnd = if let RawWindowHandle::Win32(hwnd) = hwnd {
+            hwnd.hwnd
+        } else {
+            panic!("Expected a Win32 window handle");
+        };
+
+        let data = ObsDisplayCreationData::new(hwnd.get(), 0, 0, size.width, size.height);
+        let display = self.context.display(data).unwrap();
+
+        // The main view is rendered by default
+        assert_eq!(display.get_render_target_size().unwrap(), (1920, 1080));
+
+        display
+            .set_render_target(RenderTarget::Source(&self.source))
+            .unwrap();
+        assert_eq!(display.get_render_target_size().unwrap(), (320, 240));
+
+        display
+            .set_render_target(RenderTarget::Scene(&self.scene))
+            .unwrap();
+        assert_eq!(display.get_render_target_size().unwrap(), (1920, 1080));
+
+        display.set_render_target(RenderTarget::Main).unwrap();
+        assert_eq!(display.get_render_target_size().unwrap(), (1920, 1080));
+
+        self._window = Some(Sendable(window));
+        self.display = Some(display);
+    }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        if let Some(display) = self.display.take() {
+            self.context.remove_display(&display).unwrap();
+            event_loop.exit();
+        }
+    }
+
+    fn window_event(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
+        if let WindowEvent::CloseRequested = event {
+            if let Some(display) = self.display.take() {
+                self.context.remove_display(&display).unwrap();
+            }
+
+            event_loop.exit();
+        }
+    }
+}
+
+/// Integration test: Test switching what a display renders between the main view, a scene and a source
+#[test]
+fn test_display_render_target() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let v = ObsVideoInfoBuilder::new()
+        .base_width(1920)
+        .base_height(1080)
+        .output_width(1920)
+        .output_height(1080)
+        .build();
+    let info = StartupInfo::new().set_video_info(v);
+
+    let mut context = ObsContext::new(info).unwrap();
+    let mut scene = context.scene("render_target_scene").unwrap();
+
+    let source = context
+        .source_builder::<ColorSourceBuilder, _>("render_target_color")
+        .unwrap()
+        .set_color(ObsColor::rgb(0, 255, 0))
+        .set_width(320)
+        .set_height(240)
+        .add_to_scene(&mut scene)
+        .unwrap();
+
+    scene.set_to_channel(0).unwrap();
+
+    let event_loop = winit::event_loop::EventLoop::builder()
+        .with_any_thread(true)
+        .build()
+        .expect("Failed to create event loop");
+    let mut app = App {
+        _window: None,
+        display: None,
+        context,
+        scene,
+        source,
+    };
+
+    event_loop.run_app(&mut app).unwrap();
+}
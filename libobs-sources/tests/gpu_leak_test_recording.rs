@@ -1,3 +1,4 @@
+#![cfg(windows)]
 mod common;
 
 use std::{path::PathBuf, process::Command, time::Duration};
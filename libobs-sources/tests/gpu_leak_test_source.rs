@@ -1,3 +1,4 @@
+#![cfg(windows)]
 mod common;
 
 use std::{process::Command, time::Duration};
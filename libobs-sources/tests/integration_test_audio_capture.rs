@@ -0,0 +1,76 @@
+#![cfg(windows)]
+mod common;
+
+use libobs_sources::{
+    sources::{WasapiInputCaptureSourceBuilder, WasapiOutputCaptureSourceBuilder},
+    windows::{ApplicationAudioCaptureBuilder, ObsWindowPriority, WindowSearchMode},
+};
+use libobs_wrapper::{context::ObsContext, sources::ObsSourceBuilder, utils::StartupInfo};
+
+/// Integration test: Test listing audio devices and capturing the default device
+#[test]
+pub fn test_audio_device_capture() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("audio_scene").unwrap();
+
+    let inputs = WasapiInputCaptureSourceBuilder::list_devices(context.runtime()).unwrap();
+    let outputs = WasapiOutputCaptureSourceBuilder::list_devices(context.runtime()).unwrap();
+    println!("Input devices: {:?}", inputs);
+    println!("Output devices: {:?}", outputs);
+
+    // WASAPI always lists the default device first, even if there is no device
+    let default_output = outputs
+        .iter()
+        .find(|d| d.is_default)
+        .expect("default output device is not listed");
+    assert_eq!(default_output.id, "default");
+    assert!(inputs.iter().all(|d| !d.id.is_empty()));
+
+    let source = context
+        .source_builder::<WasapiOutputCaptureSourceBuilder, _>("desktop_audio")
+        .unwrap()
+        .set_device(default_output)
+        .add_to_scene(&mut scene)
+        .unwrap();
+
+    let settings = source.settings().to_mutable().unwrap();
+    assert_eq!(
+        settings.get_string("device_id").unwrap(),
+        Some("default".to_string())
+    );
+}
+
+/// Integration test: Test capturing the audio of a single application
+#[test]
+pub fn test_application_audio_capture() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("application_audio_scene").unwrap();
+
+    let windows =
+        ApplicationAudioCaptureBuilder::get_windows(WindowSearchMode::ExcludeMinimized).unwrap();
+    let builder = context
+        .source_builder::<ApplicationAudioCaptureBuilder, _>("application_audio")
+        .unwrap()
+        .set_priority(ObsWindowPriority::Executable);
+
+    let builder = match windows.first() {
+        Some(window) => builder.set_window(window),
+        None => builder.set_window_raw("Game:GameWindowClass:game.exe"),
+    };
+
+    let source = builder.add_to_scene(&mut scene).unwrap();
+    let settings = source.settings().to_mutable().unwrap();
+    assert!(settings.get_string("window").unwrap().is_some());
+    assert_eq!(
+        settings.get_int("priority").unwrap(),
+        Some(ObsWindowPriority::Executable as i64)
+    );
+}
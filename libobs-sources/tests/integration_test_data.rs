@@ -1,4 +1,10 @@
-use libobs_wrapper::{context::ObsContext, utils::StartupInfo};
+use libobs_wrapper::{
+    context::ObsContext,
+    data::{immutable::ImmutableObsData, ObsData, ObsDataArray},
+    enums::ObsDataType,
+    utils::{ObsError, StartupInfo},
+};
+use serde::{Deserialize, Serialize};
 
 /// Integration test: Test encoder settings manipulation
 #[test]
@@ -52,3 +58,184 @@ pub fn test_data_get_nonexistent() {
     let nonexistent_double = data.get_double("nonexistent_key");
     assert_eq!(nonexistent_double, Ok(None));
 }
+
+/// Integration test: Test nested objects, arrays, defaults and key iteration
+#[test]
+pub fn test_data_nested_and_defaults() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut data = context.data().unwrap();
+
+    let mut nested = context.data().unwrap();
+    nested.set_string("file", "image.png").unwrap();
+
+    let array = ObsDataArray::from_items(&[nested], context.runtime().clone()).unwrap();
+    assert_eq!(array.len(), Ok(1));
+
+    data.set_array("files", &array).unwrap();
+    data.set_default_int("slide_time", 8000).unwrap();
+
+    let files = data.get_array("files").unwrap().unwrap();
+    let first = files.get(0).unwrap().unwrap();
+    assert_eq!(first.get_string("file"), Ok(Some("image.png".to_string())));
+    assert!(files.get(1).unwrap().is_none());
+
+    // Default values are returned, but are not user values
+    assert_eq!(data.get_int("slide_time"), Ok(Some(8000)));
+    assert_eq!(data.has_user_value("slide_time"), Ok(false));
+    assert_eq!(data.has_default_value("slide_time"), Ok(true));
+
+    data.set_int("slide_time", 2000).unwrap();
+    assert_eq!(data.has_user_value("slide_time"), Ok(true));
+
+    let items = data.items().unwrap();
+    let files_item = items.iter().find(|item| item.name() == "files").unwrap();
+    assert!(matches!(files_item.data_type(), ObsDataType::Array));
+
+    let mut keys = data.keys().unwrap();
+    keys.sort();
+    assert_eq!(keys, vec!["files".to_string(), "slide_time".to_string()]);
+
+    // Erasing only removes the user value
+    data.erase("slide_time").unwrap();
+    assert_eq!(data.get_int("slide_time"), Ok(Some(8000)));
+    assert_eq!(data.get_default_int("slide_time"), Ok(Some(8000)));
+    assert_eq!(data.has_user_value("slide_time"), Ok(false));
+
+    data.remove_with_default("slide_time").unwrap();
+    assert_eq!(data.has_default_value("slide_time"), Ok(false));
+    data.set_default_int("slide_time", 8000).unwrap();
+
+    let mut obj = context.data().unwrap();
+    obj.set_bool("enabled", true).unwrap();
+    data.bulk_update()
+        .set_obj("transform", obj)
+        .erase("files")
+        .update()
+        .unwrap();
+
+    let transform = data.get_obj("transform").unwrap().unwrap();
+    assert_eq!(transform.get_bool("enabled"), Ok(Some(true)));
+    assert!(data.get_array("files").unwrap().is_none());
+}
+
+/// Integration test: Test diffing settings snapshots and replaying the patch
+#[test]
+pub fn test_data_diff_and_patch() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+    let runtime = context.runtime().clone();
+
+    let old = ObsData::from_json(
+        r#"{"bitrate": 2500, "preset": "fast", "nested": {"a": 1}}"#,
+        runtime.clone(),
+    )
+    .unwrap();
+    let new = ObsData::from_json(
+        r#"{"bitrate": 6000, "nested": {"a": 2}, "items": [{"b": true}]}"#,
+        runtime.clone(),
+    )
+    .unwrap();
+
+    let old = ImmutableObsData::from(old);
+    let new = ImmutableObsData::from(new);
+
+    let patch = old.diff(&new).unwrap();
+    assert_eq!(patch.len(), 4);
+
+    let mut target = old.to_mutable().unwrap();
+    target
+        .bulk_update()
+        .apply_patch(&patch)
+        .unwrap()
+        .update()
+        .unwrap();
+
+    let target = ImmutableObsData::from(target);
+    assert_eq!(target.to_value().unwrap(), new.to_value().unwrap());
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum TestMode {
+    Quality,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TestEntry {
+    name: String,
+    enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TestNested {
+    ratio: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TestSettings {
+    bitrate: i64,
+    preset: String,
+    mode: TestMode,
+    nested: TestNested,
+    entries: Vec<TestEntry>,
+    optional: Option<String>,
+}
+
+/// Integration test: Test converting serde types from and to ObsData
+#[test]
+pub fn test_data_serde_roundtrip() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let settings = TestSettings {
+        bitrate: 6000,
+        preset: "fast".to_string(),
+        mode: TestMode::Quality,
+        nested: TestNested { ratio: 0.5 },
+        entries: vec![
+            TestEntry {
+                name: "first".to_string(),
+                enabled: true,
+            },
+            TestEntry {
+                name: "second".to_string(),
+                enabled: false,
+            },
+        ],
+        optional: None,
+    };
+
+    let data = ObsData::from_serialize(&settings, context.runtime().clone()).unwrap();
+    assert_eq!(data.get_int("bitrate"), Ok(Some(6000)));
+    assert_eq!(data.get_string("mode"), Ok(Some("quality".to_string())));
+
+    let deserialized: TestSettings = data.deserialize().unwrap();
+    assert_eq!(deserialized, settings);
+
+    // Only maps can be converted to ObsData
+    assert!(ObsData::from_serialize(&5, context.runtime().clone()).is_err());
+
+    // OBS would drop array items that are not maps, so they are rejected
+    #[derive(Serialize)]
+    struct TestNumbers {
+        values: Vec<i32>,
+    }
+
+    let numbers = TestNumbers {
+        values: vec![1, 2, 3],
+    };
+    assert!(matches!(
+        ObsData::from_serialize(&numbers, context.runtime().clone()),
+        Err(ObsError::SerializationError(_))
+    ));
+}
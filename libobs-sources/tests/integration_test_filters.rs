@@ -0,0 +1,87 @@
+mod common;
+
+use libobs_sources::{
+    filters::{
+        ChromaKeyFilterBuilder, ColorCorrectionFilterBuilder, CropPadFilterBuilder,
+        GainFilterBuilder, NoiseSuppressionFilterBuilder, ObsNoiseSuppressMethod,
+        ScalingFilterBuilder,
+    },
+    sources::{ColorSourceBuilder, ObsColor},
+};
+use libobs_wrapper::{
+    context::ObsContext,
+    sources::{ObsFilterBuilder, ObsSourceBuilder},
+    utils::StartupInfo,
+};
+
+/// Integration test: Test creating filters and adding them to a source
+#[test]
+pub fn test_filter_builders() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("filter_scene").unwrap();
+
+    let source = context
+        .source_builder::<ColorSourceBuilder, _>("filtered_source")
+        .unwrap()
+        .set_color(ObsColor::rgb(0, 255, 0))
+        .add_to_scene(&mut scene)
+        .unwrap();
+
+    let correction = context
+        .filter_builder::<ColorCorrectionFilterBuilder, _>("color_correction")
+        .unwrap()
+        .set_gamma(0.5)
+        .set_saturation(-1.0)
+        .add_to_source(&mut context, &source)
+        .unwrap();
+
+    assert_eq!(correction.id(), "color_filter_v2");
+    let settings = correction.settings().to_mutable().unwrap();
+    assert_eq!(settings.get_double("gamma").unwrap(), Some(0.5));
+
+    context
+        .filter_builder::<ChromaKeyFilterBuilder, _>("chroma_key")
+        .unwrap()
+        .set_key_color(ObsColor::rgb(0, 255, 0))
+        .set_similarity(400)
+        .add_to_source(&mut context, &source)
+        .unwrap();
+
+    context
+        .filter_builder::<CropPadFilterBuilder, _>("crop")
+        .unwrap()
+        .set_relative(true)
+        .set_left(10)
+        .set_bottom(-10)
+        .add_to_source(&mut context, &source)
+        .unwrap();
+
+    context
+        .filter_builder::<ScalingFilterBuilder, _>("scale")
+        .unwrap()
+        .set_resolution(640, 360)
+        .add_to_source(&mut context, &source)
+        .unwrap();
+
+    context
+        .filter_builder::<NoiseSuppressionFilterBuilder, _>("noise_suppression")
+        .unwrap()
+        .set_method(ObsNoiseSuppressMethod::RNNoise)
+        .add_to_source(&mut context, &source)
+        .unwrap();
+
+    let gain = context
+        .filter_builder::<GainFilterBuilder, _>("gain")
+        .unwrap()
+        .set_gain(6.0)
+        .add_to_source(&mut context, &source)
+        .unwrap();
+
+    let settings = gain.settings().to_mutable().unwrap();
+    assert_eq!(settings.get_double("db").unwrap(), Some(6.0));
+    assert!(context.get_filter("gain").unwrap().is_some());
+}
@@ -0,0 +1,53 @@
+#![cfg(target_os = "linux")]
+mod common;
+
+use libobs_sources::linux::{
+    ObsV4l2ColorRange, V4l2InputSourceBuilder, XshmScreenCaptureSourceBuilder,
+};
+use libobs_wrapper::{context::ObsContext, sources::ObsSourceBuilder, utils::StartupInfo};
+
+/// Integration test: Test enumerating X11 screens and V4L2 devices and creating sources for them
+#[test]
+pub fn test_linux_capture_sources() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("linux_scene").unwrap();
+
+    let screens = XshmScreenCaptureSourceBuilder::get_screens(context.runtime()).unwrap();
+    println!("Screens: {:?}", screens);
+    if let Some(screen) = screens.first() {
+        context
+            .source_builder::<XshmScreenCaptureSourceBuilder, _>("screen_capture")
+            .unwrap()
+            .set_screen(screen)
+            .set_show_cursor(true)
+            .add_to_scene(&mut scene)
+            .unwrap();
+    }
+
+    let devices = V4l2InputSourceBuilder::get_devices(context.runtime()).unwrap();
+    println!("V4L2 devices: {:?}", devices);
+    assert!(devices.iter().all(|d| d.path.starts_with("/dev/")));
+
+    let source = context
+        .source_builder::<V4l2InputSourceBuilder, _>("webcam")
+        .unwrap()
+        .set_device_id("/dev/video0")
+        .set_pixel_format(*b"YUYV")
+        .set_resolution(1280, 720)
+        .set_frame_rate(30, 1)
+        .set_color_range(ObsV4l2ColorRange::Full)
+        .add_to_scene(&mut scene)
+        .unwrap();
+
+    let settings = source.settings().to_mutable().unwrap();
+    assert_eq!(
+        settings.get_int("resolution").unwrap(),
+        Some((1280 << 16) | 720)
+    );
+    assert_eq!(settings.get_int("framerate").unwrap(), Some((1 << 16) | 30));
+    assert_eq!(settings.get_int("color_range").unwrap(), Some(2));
+}
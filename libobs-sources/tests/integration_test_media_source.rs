@@ -0,0 +1,49 @@
+mod common;
+
+use std::{path::Path, time::Duration};
+
+use libobs_sources::sources::MediaSourceBuilder;
+use libobs_wrapper::{
+    context::ObsContext,
+    enums::ObsMediaState,
+    sources::{ObsMediaControls, ObsSourceBuilder},
+    utils::StartupInfo,
+};
+
+/// Integration test: Test creating a media source and controlling its playback
+#[test]
+pub fn test_media_source_controls() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("media_scene").unwrap();
+
+    let source = context
+        .source_builder::<MediaSourceBuilder, _>("media_source")
+        .unwrap()
+        .set_file(Path::new("does_not_exist.mp4"))
+        .set_looping(true)
+        .set_hw_decode(false)
+        .set_speed_percent(150)
+        .add_to_scene(&mut scene)
+        .unwrap();
+
+    assert_eq!(source.id(), "ffmpeg_source");
+
+    let settings = source.settings().to_mutable().unwrap();
+    assert_eq!(settings.get_bool("is_local_file").unwrap(), Some(true));
+    assert_eq!(settings.get_int("speed_percent").unwrap(), Some(150));
+
+    source.play().unwrap();
+    source.pause().unwrap();
+    source.seek(Duration::from_secs(1)).unwrap();
+    source.restart().unwrap();
+    source.stop().unwrap();
+
+    // The file does not exist, so there is nothing to play
+    assert_eq!(source.get_duration().unwrap(), Duration::ZERO);
+    assert_ne!(source.get_state().unwrap(), ObsMediaState::Playing);
+    assert!(source.get_time().is_ok());
+}
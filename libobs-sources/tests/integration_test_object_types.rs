@@ -0,0 +1,41 @@
+mod common;
+
+use libobs_wrapper::{context::ObsContext, enums::ObsIconType, utils::StartupInfo};
+
+/// Integration test: Test enumerating the types registered by the loaded plugins
+#[test]
+pub fn test_available_object_types() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let sources = context.available_source_types().unwrap();
+    let color = sources
+        .iter()
+        .find(|t| t.id() == "color_source_v3")
+        .expect("color source type is not registered");
+
+    assert_eq!(*color.icon_type(), ObsIconType::Color);
+    assert!(color.output_flags().is_video());
+    assert!(!color.output_flags().is_deprecated());
+    assert!(!color.display_name().is_empty());
+
+    let filters = context.available_filter_types().unwrap();
+    assert!(!filters.is_empty());
+    assert!(filters.iter().all(|t| t.id() != "color_source_v3"));
+
+    let outputs = context.available_output_types().unwrap();
+    let muxer = outputs
+        .iter()
+        .find(|t| t.id() == "ffmpeg_muxer")
+        .expect("ffmpeg muxer output type is not registered");
+    assert!(muxer.output_flags().is_encoded());
+
+    let encoders = context.available_encoder_types().unwrap();
+    assert!(encoders.iter().any(|t| !t.is_hidden()));
+
+    assert!(!context.available_transition_types().unwrap().is_empty());
+    assert!(context.available_service_types().is_ok());
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use libobs_sources::sources::{
+    ColorSourceBuilder, ImageSourceBuilder, ObsColor, ObsFont, TextFt2SourceBuilder,
+};
+#[cfg(windows)]
+use libobs_sources::windows::{ObsTextAlign, TextGdiplusSourceBuilder};
+use libobs_wrapper::{context::ObsContext, sources::ObsSourceBuilder, utils::StartupInfo};
+
+#[test]
+pub fn test_color_conversion() {
+    let color = ObsColor::rgba(0x11, 0x22, 0x33, 0x44);
+    assert_eq!(color.to_abgr(), 0x44332211);
+    assert_eq!(ObsColor::from_abgr(0x44332211), color);
+    assert_eq!(
+        ObsColor::from_hex(0xff8800),
+        ObsColor::rgb(0xff, 0x88, 0x00)
+    );
+}
+
+/// Integration test: Test composing an overlay from color, image and text sources
+#[test]
+pub fn test_overlay_sources() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("overlay_scene").unwrap();
+
+    let color = context
+        .source_builder::<ColorSourceBuilder, _>("overlay_background")
+        .unwrap()
+        .set_color(ObsColor::rgba(255, 0, 0, 128))
+        .set_width(320)
+        .set_height(180)
+        .add_to_scene(&mut scene)
+        .unwrap();
+
+    let settings = color.settings().to_mutable().unwrap();
+    assert_eq!(settings.get_int("color").unwrap(), Some(0x800000ff));
+    assert_eq!(settings.get_int("width").unwrap(), Some(320));
+
+    context
+        .source_builder::<ImageSourceBuilder, _>("overlay_image")
+        .unwrap()
+        .set_file("does_not_exist.png")
+        .set_unload(true)
+        .add_to_scene(&mut scene)
+        .unwrap();
+
+    let font = ObsFont {
+        bold: true,
+        ..ObsFont::default()
+    };
+
+    let text = context
+        .source_builder::<TextFt2SourceBuilder, _>("overlay_text")
+        .unwrap()
+        .set_text("Hello")
+        .set_font(&font)
+        .unwrap()
+        .set_color(ObsColor::WHITE)
+        .set_outline(true)
+        .add_to_scene(&mut scene)
+        .unwrap();
+
+    let settings = text.settings().to_mutable().unwrap();
+    let font_data = settings.get_obj("font").unwrap().unwrap();
+    assert_eq!(font_data.get_int("size").unwrap(), Some(36));
+    assert_eq!(
+        font_data.get_int("flags").unwrap(),
+        Some(libobs::OBS_FONT_BOLD as i64)
+    );
+
+    #[cfg(windows)]
+    context
+        .source_builder::<TextGdiplusSourceBuilder, _>("overlay_gdiplus_text")
+        .unwrap()
+        .set_text("Hello")
+        .set_font(&font)
+        .unwrap()
+        .set_align(ObsTextAlign::Center)
+        .set_background(ObsColor::rgba(0, 0, 0, 255))
+        .add_to_scene(&mut scene)
+        .unwrap();
+}
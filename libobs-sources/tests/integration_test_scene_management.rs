@@ -1,6 +1,13 @@
 mod common;
 
-use libobs_wrapper::{context::ObsContext, utils::StartupInfo};
+use std::time::{Duration, Instant};
+
+use libobs_wrapper::{
+    context::ObsContext,
+    sources::ObsSourceRef,
+    utils::{SourceInfo, StartupInfo},
+    Vec2,
+};
 
 /// Integration test: Test creating a scene
 #[test]
@@ -91,3 +98,113 @@ pub fn test_scene_different_channels() {
     let scene1 = context.scene("channel_1_scene").unwrap();
     assert!(scene1.set_to_channel(1).is_ok());
 }
+
+/// Integration test: Test finding sources that were not created through the scene
+#[test]
+pub fn test_find_sources() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("find_scene").unwrap();
+    let source = scene
+        .add_source(SourceInfo::new(
+            "color_source_v3",
+            "find_source",
+            None,
+            None,
+        ))
+        .unwrap();
+
+    let found = context
+        .find_source_by_name("find_source")
+        .unwrap()
+        .expect("source was not found by name");
+    assert_eq!(found.id(), "color_source_v3");
+
+    let uuid = source.uuid().unwrap();
+    assert_eq!(found.uuid().unwrap(), uuid);
+
+    let found = context
+        .find_source_by_uuid(&uuid)
+        .unwrap()
+        .expect("source was not found by uuid");
+    assert_eq!(found.name(), "find_source");
+
+    assert!(context
+        .find_source_by_name("missing_source")
+        .unwrap()
+        .is_none());
+
+    let all = context.all_sources().unwrap();
+    assert!(all.iter().any(|s| s.name() == "find_source"));
+
+    // The wrappers hold their own references, so dropping them keeps the source alive
+    drop(all);
+    drop(found);
+    assert!(context
+        .find_source_by_name("find_source")
+        .unwrap()
+        .is_some());
+}
+
+/// Integration test: Test hit testing and selecting scene items
+#[test]
+pub fn test_item_hit_testing() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("hit_test_scene").unwrap();
+
+    let mut add_color_source = |name: &str| {
+        let mut settings = context.data().unwrap();
+        settings.set_int("width", 200).unwrap();
+        settings.set_int("height", 100).unwrap();
+
+        scene
+            .add_source(SourceInfo::new(
+                "color_source_v3",
+                name,
+                Some(settings),
+                None,
+            ))
+            .unwrap()
+    };
+
+    let bottom = add_color_source("hit_test_bottom");
+    let top = add_color_source("hit_test_top");
+    scene
+        .set_source_position(&top, Vec2::new(100.0, 50.0))
+        .unwrap();
+
+    // The transforms are updated on the next video tick, so wait until both items were moved
+    let bottom_right = |source: &ObsSourceRef| {
+        let corners = scene.get_source_box_transform(source).unwrap().corners();
+        (*corners[2].x(), *corners[2].y())
+    };
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while bottom_right(&top) != (300.0, 150.0) || bottom_right(&bottom) != (200.0, 100.0) {
+        assert!(
+            Instant::now() < deadline,
+            "The transforms of the items were not updated"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    // The overlapping area belongs to the item on top
+    let hit = scene.item_at(Vec2::new(150.0, 75.0)).unwrap().unwrap();
+    assert_eq!(hit.name(), "hit_test_top");
+
+    let hit = scene.item_at(Vec2::new(10.0, 10.0)).unwrap().unwrap();
+    assert_eq!(hit.name(), "hit_test_bottom");
+
+    assert!(scene.item_at(Vec2::new(500.0, 500.0)).unwrap().is_none());
+
+    assert!(!scene.is_source_selected(&bottom).unwrap());
+    scene.set_source_selected(&bottom, true).unwrap();
+    assert!(scene.is_source_selected(&bottom).unwrap());
+}
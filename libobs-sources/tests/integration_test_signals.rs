@@ -0,0 +1,112 @@
+mod common;
+
+use libobs_wrapper::{
+    context::ObsContext,
+    utils::{traits::ObsUpdatable, SourceInfo, StartupInfo},
+};
+
+/// Integration test: Test subscribing to a signal by name and reading its calldata
+#[test]
+pub fn test_dynamic_signal() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("dynamic_signal_scene").unwrap();
+
+    let mut rx = scene.signals().connect_dynamic("item_add").unwrap();
+    let source = scene
+        .add_source(SourceInfo::new(
+            "color_source_v3",
+            "dynamic_signal_source",
+            None,
+            None,
+        ))
+        .unwrap();
+
+    let calldata = rx.try_recv().expect("item_add signal was not received");
+    assert!(calldata.get_ptr("item").is_some());
+    assert!(calldata.get_ptr("scene").is_some());
+
+    // A second wrapper of the same source must not disconnect the signals of the first one
+    let mut rx = source.signal_manager().connect_dynamic("update").unwrap();
+    let mut on_update = source.signal_manager().on_update().unwrap();
+    let mut settings = context.data().unwrap();
+    settings.set_int("width", 100).unwrap();
+
+    let mut source = source;
+    source.update_raw(settings).unwrap();
+    assert!(on_update.try_recv().is_ok());
+
+    let calldata = rx.try_recv().expect("update signal was not received");
+    // The source is still alive, as this test holds a reference to it
+    let wrapped = unsafe { calldata.get_source("source", context.runtime().clone()) }
+        .unwrap()
+        .expect("source pointer was not set");
+
+    assert_eq!(wrapped.name(), "dynamic_signal_source");
+    drop(wrapped);
+
+    let settings = context.data().unwrap();
+    source.update_raw(settings).unwrap();
+    assert!(on_update.try_recv().is_ok());
+}
+
+/// Integration test: Test receiving source lifecycle events from the global signal handler
+#[test]
+pub fn test_global_signals() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut on_create = context.global_signals().on_source_create().unwrap();
+
+    let mut scene = context.scene("global_signal_scene").unwrap();
+    let source = scene
+        .add_source(SourceInfo::new(
+            "color_source_v3",
+            "global_signal_source",
+            None,
+            None,
+        ))
+        .unwrap();
+
+    let mut created = Vec::new();
+    while let Ok(signal) = on_create.try_recv() {
+        created.push(signal.source.0);
+    }
+
+    // The scene itself is a source as well
+    assert_eq!(created.len(), 2);
+    assert!(created.contains(&scene.get_scene_source_ptr().unwrap().0));
+    drop(source);
+}
+
+/// Integration test: Test receiving hotkey signals, scene items register show and hide hotkeys
+#[test]
+pub fn test_global_hotkey_signals() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut on_register = context.global_signals().on_hotkey_register().unwrap();
+
+    let mut scene = context.scene("hotkey_signal_scene").unwrap();
+    let source = scene
+        .add_source(SourceInfo::new(
+            "color_source_v3",
+            "hotkey_signal_source",
+            None,
+            None,
+        ))
+        .unwrap();
+
+    let signal = on_register
+        .try_recv()
+        .expect("hotkey_register signal was not received");
+    assert!(!signal.key.0.is_null());
+    drop(source);
+}
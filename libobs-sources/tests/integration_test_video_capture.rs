@@ -0,0 +1,68 @@
+mod common;
+
+use libobs_sources::sources::{VideoCaptureDeviceBuilder, VideoCaptureMode};
+use libobs_wrapper::{
+    context::ObsContext, enums::ObsVideoRange, sources::ObsSourceBuilder, utils::StartupInfo,
+};
+
+/// Integration test: Test enumerating video capture devices and their modes
+#[test]
+pub fn test_video_capture_device() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("video_capture_scene").unwrap();
+
+    let devices = VideoCaptureDeviceBuilder::get_devices(context.runtime()).unwrap();
+    println!("Video capture devices: {:?}", devices);
+
+    let mut builder = context
+        .source_builder::<VideoCaptureDeviceBuilder, _>("webcam")
+        .unwrap()
+        .set_buffering(false)
+        .set_color_range(ObsVideoRange::Full);
+
+    // CI machines usually do not have a camera
+    if let Some(device) = devices.first() {
+        let modes = VideoCaptureDeviceBuilder::get_modes(device, context.runtime()).unwrap();
+        println!("Modes of {}: {:?}", device.name, modes);
+        assert!(modes
+            .iter()
+            .all(|m| m.width > 0 && m.height > 0 && m.frame_rate > 0.0));
+
+        builder = builder.set_device(device);
+        if let Some(mode) = VideoCaptureMode::best_match(&modes, 1280, 720, 30.0) {
+            let closest = modes
+                .iter()
+                .map(|m| m.width.abs_diff(1280) + m.height.abs_diff(720))
+                .min()
+                .unwrap();
+            assert_eq!(
+                mode.width.abs_diff(1280) + mode.height.abs_diff(720),
+                closest
+            );
+
+            builder = builder.set_mode(mode);
+        }
+    }
+
+    let source = builder.add_to_scene(&mut scene).unwrap();
+    let settings = source.settings().to_mutable().unwrap();
+
+    #[cfg(windows)]
+    {
+        assert_eq!(settings.get_int("buffering").unwrap(), Some(2));
+        assert_eq!(
+            settings.get_string("color_range").unwrap(),
+            Some("full".to_string())
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        assert_eq!(settings.get_bool("buffering").unwrap(), Some(false));
+        assert_eq!(settings.get_int("color_range").unwrap(), Some(2));
+    }
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use libobs_sources::output::VirtualCamOutputBuilder;
+use libobs_wrapper::{
+    context::ObsContext,
+    data::ObsObjectBuilder,
+    utils::{SourceInfo, StartupInfo},
+};
+
+/// Integration test: Test starting and stopping the virtual camera if its driver is installed
+#[test]
+pub fn test_virtual_cam_output() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let available = VirtualCamOutputBuilder::is_available(context.runtime()).unwrap();
+    let registered = context
+        .available_output_types()
+        .unwrap()
+        .iter()
+        .any(|t| t.id() == "virtualcam_output");
+    assert_eq!(available, registered);
+
+    if !available {
+        println!("Virtual camera driver is not installed, skipping");
+        return;
+    }
+
+    let mut scene = context.scene("virtual_cam_scene").unwrap();
+    scene
+        .add_source(SourceInfo::new(
+            "color_source_v3",
+            "virtual_cam_source",
+            None,
+            None,
+        ))
+        .unwrap();
+    scene.set_to_channel(0).unwrap();
+
+    let info = VirtualCamOutputBuilder::new("virtual_cam", context.runtime().clone())
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut output = context.output(info).unwrap();
+
+    output.start().unwrap();
+    assert!(output.is_active().unwrap());
+
+    output.stop().unwrap();
+    assert!(!output.is_active().unwrap());
+}
@@ -43,16 +43,23 @@ use std::{
 };
 
 use crate::{
-    data::{output::ObsOutputRef, video::ObsVideoInfo, ObsData},
-    display::{ObsDisplayCreationData, ObsDisplayRef},
+    data::{call_proc, output::ObsOutputRef, video::ObsVideoInfo, CallData, ObsData},
+    display::{
+        ObsDisplayCreationData, ObsDisplayRef, ObsOffscreenPreview, ObsOffscreenPreviewCreationData,
+    },
     enums::{ObsLogLevel, ObsResetVideoStatus},
     logger::LOGGER,
     run_with_obs,
     runtime::ObsRuntime,
     scenes::ObsSceneRef,
-    sources::{ObsFilterRef, ObsSourceBuilder},
+    signals::ObsGlobalSignals,
+    sources::{ObsFilterBuilder, ObsFilterRef, ObsSourceBuilder, ObsSourceRef},
     unsafe_send::Sendable,
-    utils::{FilterInfo, ObsError, ObsModules, ObsString, OutputInfo, StartupInfo},
+    utils::{
+        object_types::{self, ObsSourceKind},
+        FilterInfo, ObsEncoderTypeInfo, ObsError, ObsModules, ObsOutputTypeInfo,
+        ObsServiceTypeInfo, ObsSourceTypeInfo, ObsString, OutputInfo, StartupInfo,
+    },
 };
 use getters0::Getters;
 use libobs::{audio_output, obs_scene_t, video_output};
@@ -103,6 +110,10 @@ pub struct ObsContext {
     #[get_mut]
     pub(crate) filters: Arc<RwLock<Vec<ObsFilterRef>>>,
 
+    /// Signals of the global signal handler
+    #[skip_getter]
+    global_signals: Arc<ObsGlobalSignals>,
+
     #[skip_getter]
     pub(crate) active_scene: Arc<RwLock<Option<Sendable<*mut obs_scene_t>>>>,
 
@@ -139,7 +150,13 @@ impl ObsContext {
         // Spawning runtime, I'll keep this as function for now
         let (runtime, obs_modules, info) = ObsRuntime::startup(info)?;
 
+        let handler = run_with_obs!(runtime, || unsafe {
+            Sendable(libobs::obs_get_signal_handler())
+        })?;
+
+        let global_signals = ObsGlobalSignals::new(&handler, runtime.clone())?;
         Ok(Self {
+            global_signals: Arc::new(global_signals),
             _obs_modules: Arc::new(obs_modules),
             active_scene: Default::default(),
             displays: Default::default(),
@@ -162,6 +179,55 @@ impl ObsContext {
         Ok(res)
     }
 
+    /// Returns the signals of the global libobs signal handler, which are emitted for all
+    /// sources and hotkeys (for example when a plugin creates a source).
+    pub fn global_signals(&self) -> Arc<ObsGlobalSignals> {
+        self.global_signals.clone()
+    }
+
+    /// Calls the procedure with the given name of the global libobs proc handler and returns
+    /// the calldata the procedure has written to.
+    ///
+    /// Note that encoders don't have a proc handler in libobs, so procedures of sources and
+    /// outputs are called through `ObsSourceRef::call_proc` and `ObsOutputRef::call_proc`.
+    pub fn call_proc(&self, name: &str, calldata: CallData) -> Result<CallData, ObsError> {
+        let handler = run_with_obs!(self.runtime, || unsafe {
+            Sendable(libobs::obs_get_proc_handler())
+        })?;
+
+        call_proc(handler, name, calldata, &self.runtime)
+    }
+
+    /// Returns the input source types registered by the loaded plugins.
+    pub fn available_source_types(&self) -> Result<Vec<ObsSourceTypeInfo>, ObsError> {
+        object_types::source_types(ObsSourceKind::Input, &self.runtime)
+    }
+
+    /// Returns the filter types registered by the loaded plugins.
+    pub fn available_filter_types(&self) -> Result<Vec<ObsSourceTypeInfo>, ObsError> {
+        object_types::source_types(ObsSourceKind::Filter, &self.runtime)
+    }
+
+    /// Returns the transition types registered by the loaded plugins.
+    pub fn available_transition_types(&self) -> Result<Vec<ObsSourceTypeInfo>, ObsError> {
+        object_types::source_types(ObsSourceKind::Transition, &self.runtime)
+    }
+
+    /// Returns the output types registered by the loaded plugins.
+    pub fn available_output_types(&self) -> Result<Vec<ObsOutputTypeInfo>, ObsError> {
+        object_types::output_types(&self.runtime)
+    }
+
+    /// Returns the encoder types registered by the loaded plugins, including hidden encoders.
+    pub fn available_encoder_types(&self) -> Result<Vec<ObsEncoderTypeInfo>, ObsError> {
+        object_types::encoder_types(&self.runtime)
+    }
+
+    /// Returns the service types registered by the loaded plugins.
+    pub fn available_service_types(&self) -> Result<Vec<ObsServiceTypeInfo>, ObsError> {
+        object_types::service_types(&self.runtime)
+    }
+
     pub fn log(&self, level: ObsLogLevel, msg: &str) {
         let mut log = LOGGER.lock().unwrap();
         log.log(level, msg.to_string());
@@ -368,6 +434,17 @@ impl ObsContext {
         Ok(d)
     }
 
+    /// Creates a preview that renders into a texture instead of a window
+    /// and delivers the frames as RGBA pixels.
+    ///
+    /// The preview is not stored in the context, rendering stops once it is dropped.
+    pub fn offscreen_preview(
+        &self,
+        data: ObsOffscreenPreviewCreationData,
+    ) -> Result<ObsOffscreenPreview, ObsError> {
+        ObsOffscreenPreview::new(data, self.runtime.clone())
+    }
+
     pub fn get_output(&mut self, name: &str) -> Result<Option<ObsOutputRef>, ObsError> {
         let o = self
             .outputs
@@ -395,6 +472,84 @@ impl ObsContext {
         }
     }
 
+    /// Returns the source with the given name. Unlike `get_filter`, this also finds sources that
+    /// were not created through this context, for example sources created by plugins or by
+    /// loading a scene collection.
+    pub fn find_source_by_name(&self, name: &str) -> Result<Option<ObsSourceRef>, ObsError> {
+        let name = ObsString::new(name);
+        let name_ptr = name.as_ptr();
+
+        let source = run_with_obs!(self.runtime, (name_ptr), move || unsafe {
+            Sendable(libobs::obs_get_source_by_name(name_ptr))
+        })?;
+
+        if source.0.is_null() {
+            return Ok(None);
+        }
+
+        ObsSourceRef::from_raw(source, self.runtime.clone()).map(Some)
+    }
+
+    /// Returns the source with the given uuid, see `find_source_by_name`.
+    pub fn find_source_by_uuid(&self, uuid: &str) -> Result<Option<ObsSourceRef>, ObsError> {
+        let uuid = ObsString::new(uuid);
+        let uuid_ptr = uuid.as_ptr();
+
+        let source = run_with_obs!(self.runtime, (uuid_ptr), move || unsafe {
+            Sendable(libobs::obs_get_source_by_uuid(uuid_ptr))
+        })?;
+
+        if source.0.is_null() {
+            return Ok(None);
+        }
+
+        ObsSourceRef::from_raw(source, self.runtime.clone()).map(Some)
+    }
+
+    /// Returns all public input sources known to libobs, including sources that were not
+    /// created through this context. Scenes, filters and transitions are not included.
+    pub fn all_sources(&self) -> Result<Vec<ObsSourceRef>, ObsError> {
+        unsafe extern "C" fn enum_source(
+            param: *mut std::ffi::c_void,
+            source: *mut libobs::obs_source_t,
+        ) -> bool {
+            // The pointers are only valid during the enumeration, so a reference is taken
+            let source = libobs::obs_source_get_ref(source);
+            if !source.is_null() {
+                let sources = &mut *(param as *mut Vec<Sendable<*mut libobs::obs_source_t>>);
+                sources.push(Sendable(source));
+            }
+
+            true
+        }
+
+        let sources = run_with_obs!(self.runtime, move || unsafe {
+            let mut sources: Vec<Sendable<*mut libobs::obs_source_t>> = Vec::new();
+            libobs::obs_enum_sources(
+                Some(enum_source),
+                &mut sources as *mut _ as *mut std::ffi::c_void,
+            );
+
+            sources
+        })?;
+
+        // Every pointer holds a reference now, so all of them have to be wrapped
+        // even if wrapping one of them fails
+        let mut result = Vec::with_capacity(sources.len());
+        let mut error = None;
+        for source in sources {
+            match ObsSourceRef::from_raw(source, self.runtime.clone()) {
+                Ok(source) => result.push(source),
+                Err(e) => error = Some(e),
+            }
+        }
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(result),
+        }
+    }
+
     pub fn get_filter(&mut self, name: &str) -> Result<Option<ObsFilterRef>, ObsError> {
         let f = self
             .filters
@@ -439,4 +594,11 @@ impl ObsContext {
     ) -> Result<T, ObsError> {
         T::new(name.into(), self.runtime.clone())
     }
+
+    pub fn filter_builder<T: ObsFilterBuilder, K: Into<ObsString> + Send + Sync>(
+        &self,
+        name: K,
+    ) -> Result<T, ObsError> {
+        T::new(name.into(), self.runtime.clone())
+    }
 }
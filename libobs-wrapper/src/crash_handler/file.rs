@@ -0,0 +1,342 @@
+use std::{
+    ffi::{c_void, CStr},
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use chrono::Local;
+
+use crate::logger::{timestamped_name_sort_key, MemoryLogHandle, ObsLogQuery};
+
+use super::ObsCrashHandler;
+
+const REPORT_PREFIX: &str = "crash-";
+const REPORT_EXTENSION: &str = ".txt";
+const UPLOADED_DIR: &str = "uploaded";
+/// How long to wait for libobs functions that take a lock while writing a report
+const LOCK_TIMEOUT: Duration = Duration::from_millis(500);
+
+type UploadHook = Box<dyn Fn(&Path, &str) -> anyhow::Result<()> + Send>;
+
+/// A crash handler that writes a crash report to a directory.
+///
+/// The report contains the crash message, a Rust backtrace, the loaded modules, the current
+/// video info, the active outputs and (if a `MemoryLogHandle` is set) the most recent log lines.
+///
+/// Reports that were not uploaded yet are passed to the upload hook when the handler is
+/// installed with `install`, which is usually done on the next startup of the application.
+/// Uploaded reports are moved to the `uploaded` subdirectory.
+pub struct FileCrashHandler {
+    dir: PathBuf,
+    log_handle: Option<MemoryLogHandle>,
+    log_lines: usize,
+    upload_hook: Option<UploadHook>,
+}
+
+impl FileCrashHandler {
+    /// Creates a crash handler that writes reports to the given directory
+    pub fn new<T: Into<PathBuf>>(dir: T) -> Self {
+        Self {
+            dir: dir.into(),
+            log_handle: None,
+            log_lines: 200,
+            upload_hook: None,
+        }
+    }
+
+    /// Includes the newest `lines` log lines of the given `MemoryLogger` in the report
+    pub fn with_log_handle(mut self, handle: MemoryLogHandle, lines: usize) -> Self {
+        self.log_handle = Some(handle);
+        self.log_lines = lines;
+        self
+    }
+
+    /// Sets a hook that is called with the path and the content of every report that was not
+    /// uploaded yet. If the hook returns `Ok`, the report is marked as uploaded.
+    pub fn with_upload_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&Path, &str) -> anyhow::Result<()> + Send + 'static,
+    {
+        self.upload_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Passes pending reports to the upload hook and sets this handler as the global crash
+    /// handler.
+    pub fn install(self) {
+        if let Err(e) = self.upload_pending_reports() {
+            log::warn!("Failed to upload pending crash reports: {:?}", e);
+        }
+
+        super::set_crash_handler(Box::new(self));
+    }
+
+    /// Returns the reports in the directory that were not uploaded yet, oldest first.
+    pub fn pending_reports(&self) -> anyhow::Result<Vec<PathBuf>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut reports = fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .filter(|path| {
+                let name = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or_default();
+                name.starts_with(REPORT_PREFIX) && name.ends_with(REPORT_EXTENSION)
+            })
+            .collect::<Vec<_>>();
+
+        // Sorted by age, so `crash-<time>.txt` comes before `crash-<time>-1.txt`
+        reports.sort_by_key(|path| {
+            let name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default();
+            let stem = name
+                .trim_end_matches(REPORT_EXTENSION)
+                .trim_start_matches(REPORT_PREFIX);
+
+            timestamped_name_sort_key(stem)
+        });
+
+        Ok(reports)
+    }
+
+    /// Passes all pending reports to the upload hook and returns the number of uploaded reports.
+    pub fn upload_pending_reports(&self) -> anyhow::Result<usize> {
+        let hook = match &self.upload_hook {
+            Some(hook) => hook,
+            None => return Ok(0),
+        };
+
+        let mut uploaded = 0;
+        for report in self.pending_reports()? {
+            let content = fs::read_to_string(&report)?;
+            if let Err(e) = hook(&report, &content) {
+                log::warn!(
+                    "Failed to upload crash report {}: {:?}",
+                    report.display(),
+                    e
+                );
+                continue;
+            }
+
+            let uploaded_dir = self.dir.join(UPLOADED_DIR);
+            fs::create_dir_all(&uploaded_dir)?;
+            if let Some(name) = report.file_name() {
+                fs::rename(&report, uploaded_dir.join(name))?;
+            }
+
+            uploaded += 1;
+        }
+
+        Ok(uploaded)
+    }
+
+    /// Builds the content of a crash report for the given message.
+    ///
+    /// # Safety
+    /// The state of libobs is read directly, bypassing the OBS runtime. libobs must either not
+    /// be initialized or stay initialized until this returns, so it must not be shut down
+    /// concurrently.
+    pub unsafe fn build_report(&self, message: &str) -> String {
+        let mut report = String::new();
+
+        let _ = writeln!(report, "OBS crashed at {}", Local::now().to_rfc3339());
+        let _ = writeln!(report);
+        let _ = writeln!(report, "== Crash message ==");
+        let _ = writeln!(report, "{}", message);
+        let _ = writeln!(report);
+
+        let _ = writeln!(report, "== Backtrace ==");
+        let _ = writeln!(report, "{}", std::backtrace::Backtrace::force_capture());
+        let _ = writeln!(report);
+
+        // SAFETY: The caller ensures that libobs is not shut down concurrently
+        report.push_str(&unsafe { collect_obs_state() });
+
+        if let Some(handle) = &self.log_handle {
+            let _ = writeln!(report, "== Recent logs ==");
+
+            // The crashing thread might be the one holding the lock of the logger
+            match handle.try_export(&ObsLogQuery::new().limit(self.log_lines)) {
+                Some(logs) => report.push_str(&logs),
+                None => {
+                    let _ = writeln!(report, "(skipped, the logs are locked)");
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Writes a crash report for the given message and returns the path of the report.
+    ///
+    /// # Safety
+    /// Same as `build_report`: libobs must not be shut down concurrently.
+    pub unsafe fn write_report(&self, message: &str) -> anyhow::Result<PathBuf> {
+        fs::create_dir_all(&self.dir)?;
+
+        let time = Local::now().format("%Y-%m-%d-%H-%M-%S");
+        let mut path = self
+            .dir
+            .join(format!("{}{}{}", REPORT_PREFIX, time, REPORT_EXTENSION));
+
+        let mut counter = 1;
+        while path.exists() {
+            path = self.dir.join(format!(
+                "{}{}-{}{}",
+                REPORT_PREFIX, time, counter, REPORT_EXTENSION
+            ));
+            counter += 1;
+        }
+
+        fs::write(&path, unsafe { self.build_report(message) })?;
+        Ok(path)
+    }
+}
+
+impl ObsCrashHandler for FileCrashHandler {
+    fn handle_crash(&self, message: String) {
+        eprintln!("OBS crashed: {}", message);
+        // SAFETY: libobs calls the crash handler before it exits, so it is not shut down
+        // while the report is written
+        match unsafe { self.write_report(&message) } {
+            Ok(path) => eprintln!("Crash report written to {}", path.display()),
+            Err(e) => eprintln!("Failed to write crash report: {:?}", e),
+        }
+    }
+}
+
+/// Runs the given function on a separate thread and returns its result, or `None` if it did
+/// not finish in time.
+///
+/// Used for libobs functions that take a mutex, which the crashing thread might hold. Waiting
+/// for them directly would deadlock the crash handler. A thread that never finishes is leaked,
+/// which is fine as the process is about to exit.
+fn run_with_timeout<T, F>(f: F) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new()
+        .name("obs-crash-report".to_string())
+        .spawn(move || {
+            let _ = sender.send(f());
+        })
+        .ok()?;
+
+    receiver.recv_timeout(LOCK_TIMEOUT).ok()
+}
+
+/// Reads the loaded modules, the video info and the active outputs directly from libobs.
+///
+/// The OBS runtime is not used here, as the OBS thread might be the one that crashed. Sections
+/// that need a lock of libobs are skipped if the lock can't be taken in time.
+///
+/// # Safety
+/// Must only be called while libobs is not shut down concurrently.
+unsafe fn collect_obs_state() -> String {
+    let mut report = String::new();
+    if !libobs::obs_initialized() {
+        let _ = writeln!(report, "OBS was not initialized");
+        let _ = writeln!(report);
+        return report;
+    }
+
+    unsafe fn to_string(ptr: *const std::os::raw::c_char) -> String {
+        if ptr.is_null() {
+            return String::from("(null)");
+        }
+
+        CStr::from_ptr(ptr).to_string_lossy().to_string()
+    }
+
+    unsafe extern "C" fn enum_module(param: *mut c_void, module: *mut libobs::obs_module_t) {
+        let modules = &mut *(param as *mut Vec<String>);
+        modules.push(format!(
+            "{} ({})",
+            to_string(libobs::obs_get_module_name(module)),
+            to_string(libobs::obs_get_module_file_name(module))
+        ));
+    }
+
+    unsafe extern "C" fn enum_output(
+        param: *mut c_void,
+        output: *mut libobs::obs_output_t,
+    ) -> bool {
+        if libobs::obs_output_active(output) {
+            let outputs = &mut *(param as *mut Vec<String>);
+            outputs.push(format!(
+                "{} ({})",
+                to_string(libobs::obs_output_get_name(output)),
+                to_string(libobs::obs_output_get_id(output))
+            ));
+        }
+
+        true
+    }
+
+    let modules = run_with_timeout(|| unsafe {
+        let mut modules: Vec<String> = Vec::new();
+        libobs::obs_enum_modules(Some(enum_module), &mut modules as *mut _ as *mut c_void);
+        modules
+    });
+
+    let _ = writeln!(report, "== Loaded modules ==");
+    write_section(&mut report, modules);
+
+    let _ = writeln!(report, "== Video info ==");
+    let mut ovi: libobs::obs_video_info = std::mem::zeroed();
+    if libobs::obs_get_video_info(&mut ovi) {
+        let _ = writeln!(
+            report,
+            "Graphics module: {}",
+            to_string(ovi.graphics_module)
+        );
+        let _ = writeln!(report, "FPS: {}/{}", ovi.fps_num, ovi.fps_den);
+        let _ = writeln!(report, "Base: {}x{}", ovi.base_width, ovi.base_height);
+        let _ = writeln!(report, "Output: {}x{}", ovi.output_width, ovi.output_height);
+        let _ = writeln!(report, "Output format: {}", ovi.output_format);
+        let _ = writeln!(report, "Adapter: {}", ovi.adapter);
+    } else {
+        let _ = writeln!(report, "Video is not initialized");
+    }
+    let _ = writeln!(report);
+
+    let outputs = run_with_timeout(|| unsafe {
+        let mut outputs: Vec<String> = Vec::new();
+        libobs::obs_enum_outputs(Some(enum_output), &mut outputs as *mut _ as *mut c_void);
+        outputs
+    });
+
+    let _ = writeln!(report, "== Active outputs ==");
+    write_section(&mut report, outputs);
+
+    report
+}
+
+/// Writes the lines of a section, or a note if they could not be collected.
+fn write_section(report: &mut String, lines: Option<Vec<String>>) {
+    match lines {
+        Some(lines) => {
+            for line in &lines {
+                let _ = writeln!(report, "{}", line);
+            }
+        }
+        None => {
+            let _ = writeln!(report, "(skipped, libobs is locked)");
+        }
+    }
+
+    let _ = writeln!(report);
+}
@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::PathBuf,
+        sync::{Arc, Mutex},
+    };
+
+    use super::super::FileCrashHandler;
+    use crate::{
+        enums::ObsLogLevel,
+        logger::{MemoryLogger, ObsLogger},
+    };
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "libobs-wrapper-crash-{}-{}",
+            name,
+            std::process::id()
+        ));
+
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_crash_report_contains_message_and_logs() {
+        let dir = test_dir("report");
+
+        let mut logger = MemoryLogger::new(10);
+        for i in 0..5 {
+            logger.log(ObsLogLevel::Info, format!("[test] line {}", i));
+        }
+
+        let handler = FileCrashHandler::new(&dir).with_log_handle(logger.handle(), 2);
+        // OBS is never initialized in unit tests
+        let path = unsafe { handler.write_report("something went wrong") }.unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("something went wrong"));
+        assert!(content.contains("== Backtrace =="));
+        assert!(content.contains("line 4"));
+        assert!(content.contains("line 3"));
+        assert!(!content.contains("line 2"));
+
+        assert_eq!(handler.pending_reports().unwrap(), vec![path]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_crash_report_upload() {
+        let dir = test_dir("upload");
+        fs::write(dir.join("crash-2024-01-01-00-00-00.txt"), "first").unwrap();
+        fs::write(dir.join("crash-2024-01-02-00-00-00.txt"), "second").unwrap();
+        fs::write(dir.join("other.txt"), "not a report").unwrap();
+
+        let uploaded = Arc::new(Mutex::new(Vec::new()));
+        let hook_uploaded = uploaded.clone();
+        let handler = FileCrashHandler::new(&dir).with_upload_hook(move |_path, content| {
+            hook_uploaded.lock().unwrap().push(content.to_string());
+            Ok(())
+        });
+
+        assert_eq!(handler.upload_pending_reports().unwrap(), 2);
+        assert_eq!(*uploaded.lock().unwrap(), vec!["first", "second"]);
+
+        // Uploaded reports are not uploaded again
+        assert!(handler.pending_reports().unwrap().is_empty());
+        assert_eq!(handler.upload_pending_reports().unwrap(), 0);
+        assert!(dir
+            .join("uploaded")
+            .join("crash-2024-01-01-00-00-00.txt")
+            .exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_failed_upload_keeps_report() {
+        let dir = test_dir("failed-upload");
+        fs::write(dir.join("crash-2024-01-01-00-00-00.txt"), "report").unwrap();
+
+        let handler = FileCrashHandler::new(&dir)
+            .with_upload_hook(|_path, _content| Err(anyhow::anyhow!("offline")));
+
+        assert_eq!(handler.upload_pending_reports().unwrap(), 0);
+        assert_eq!(handler.pending_reports().unwrap().len(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_pending_reports_sorted_by_age() {
+        let dir = test_dir("sorted");
+        for name in [
+            "crash-2024-01-01-00-00-00-10.txt",
+            "crash-2024-01-01-00-00-00-2.txt",
+            "crash-2024-01-02-00-00-00.txt",
+            "crash-2024-01-01-00-00-00-1.txt",
+            "crash-2024-01-01-00-00-00.txt",
+        ] {
+            fs::write(dir.join(name), "report").unwrap();
+        }
+
+        let handler = FileCrashHandler::new(&dir);
+        let reports = handler
+            .pending_reports()
+            .unwrap()
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            reports,
+            vec![
+                "crash-2024-01-01-00-00-00.txt",
+                "crash-2024-01-01-00-00-00-1.txt",
+                "crash-2024-01-01-00-00-00-2.txt",
+                "crash-2024-01-01-00-00-00-10.txt",
+                "crash-2024-01-02-00-00-00.txt",
+            ]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
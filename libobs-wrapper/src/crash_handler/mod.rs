@@ -4,6 +4,11 @@ use lazy_static::lazy_static;
 
 #[cfg(feature = "dialog_crash_handler")]
 pub mod dialog;
+mod file;
+pub use file::FileCrashHandler;
+
+#[cfg(test)]
+mod file_tests;
 
 pub trait ObsCrashHandler: Send {
     fn handle_crash(&self, message: String);
@@ -47,6 +52,11 @@ lazy_static! {
     };
 }
 
+/// Replaces the global crash handler that is called when OBS crashes.
+pub fn set_crash_handler(handler: Box<dyn ObsCrashHandler>) {
+    *CRASH_HANDLER.lock().unwrap() = handler;
+}
+
 pub(crate) unsafe extern "C" fn main_crash_handler(
     format: *const i8,
     args: *mut i8,
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use libobs::obs_data_array;
+
+use crate::{
+    impl_obs_drop, run_with_obs, runtime::ObsRuntime, unsafe_send::Sendable, utils::ObsError,
+};
+
+use super::ObsData;
+
+#[derive(Debug)]
+pub(crate) struct _ObsDataArrayDropGuard {
+    array: Sendable<*mut obs_data_array>,
+    pub(crate) runtime: ObsRuntime,
+}
+
+impl_obs_drop!(_ObsDataArrayDropGuard, (array), move || unsafe {
+    libobs::obs_data_array_release(array)
+});
+
+/// Wrapper around `obs_data_array_t`, which is a list of `ObsData` objects.
+///
+/// Note that OBS only supports objects as items of an array.
+#[derive(Debug, Clone)]
+pub struct ObsDataArray {
+    array: Sendable<*mut obs_data_array>,
+    pub(crate) runtime: ObsRuntime,
+    _drop_guard: Arc<_ObsDataArrayDropGuard>,
+}
+
+impl ObsDataArray {
+    /// Creates a new empty array.
+    pub fn new(runtime: ObsRuntime) -> Result<Self, ObsError> {
+        let array = run_with_obs!(runtime, move || unsafe {
+            Sendable(libobs::obs_data_array_create())
+        })?;
+
+        if array.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        Ok(Self::from_raw(array, runtime))
+    }
+
+    /// Creates a new array containing the given items.
+    pub fn from_items(items: &[ObsData], runtime: ObsRuntime) -> Result<Self, ObsError> {
+        let array = Self::new(runtime)?;
+        for item in items {
+            array.push(item)?;
+        }
+
+        Ok(array)
+    }
+
+    /// Wraps the given array pointer. This takes ownership of one reference of the array,
+    /// so the array is released when this wrapper is dropped.
+    pub(crate) fn from_raw(array: Sendable<*mut obs_data_array>, runtime: ObsRuntime) -> Self {
+        Self {
+            array: array.clone(),
+            runtime: runtime.clone(),
+            _drop_guard: Arc::new(_ObsDataArrayDropGuard { array, runtime }),
+        }
+    }
+
+    /// Returns a pointer to the raw `obs_data_array`.
+    pub fn as_ptr(&self) -> Sendable<*mut obs_data_array> {
+        self.array.clone()
+    }
+
+    /// Returns the number of items in the array.
+    pub fn len(&self) -> Result<usize, ObsError> {
+        let array_ptr = self.array.clone();
+        run_with_obs!(self.runtime, (array_ptr), move || unsafe {
+            libobs::obs_data_array_count(array_ptr)
+        })
+    }
+
+    pub fn is_empty(&self) -> Result<bool, ObsError> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Returns the item at the given index or `None` if the index is out of bounds.
+    ///
+    /// The returned `ObsData` points to the same object as the array item, so changes to it
+    /// are reflected in the array.
+    pub fn get(&self, index: usize) -> Result<Option<ObsData>, ObsError> {
+        let array_ptr = self.array.clone();
+        let item = run_with_obs!(self.runtime, (array_ptr), move || unsafe {
+            Sendable(libobs::obs_data_array_item(array_ptr, index))
+        })?;
+
+        if item.0.is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(ObsData::from_raw(item, self.runtime.clone())))
+    }
+
+    /// Returns all items of the array.
+    pub fn items(&self) -> Result<Vec<ObsData>, ObsError> {
+        let array_ptr = self.array.clone();
+        let items = run_with_obs!(self.runtime, (array_ptr), move || unsafe {
+            let count = libobs::obs_data_array_count(array_ptr);
+            (0..count)
+                .map(|i| Sendable(libobs::obs_data_array_item(array_ptr, i)))
+                .filter(|item| !item.0.is_null())
+                .collect::<Vec<_>>()
+        })?;
+
+        Ok(items
+            .into_iter()
+            .map(|item| ObsData::from_raw(item, self.runtime.clone()))
+            .collect())
+    }
+
+    /// Appends the given item to the array and returns its index.
+    pub fn push(&self, item: &ObsData) -> Result<usize, ObsError> {
+        let array_ptr = self.array.clone();
+        let item_ptr = item.as_ptr();
+        run_with_obs!(self.runtime, (array_ptr, item_ptr), move || unsafe {
+            libobs::obs_data_array_push_back(array_ptr, item_ptr)
+        })
+    }
+
+    /// Inserts the given item at the given index.
+    pub fn insert(&self, index: usize, item: &ObsData) -> Result<(), ObsError> {
+        let array_ptr = self.array.clone();
+        let item_ptr = item.as_ptr();
+        run_with_obs!(self.runtime, (array_ptr, item_ptr), move || unsafe {
+            libobs::obs_data_array_insert(array_ptr, index, item_ptr)
+        })
+    }
+
+    /// Removes the item at the given index.
+    pub fn erase(&self, index: usize) -> Result<(), ObsError> {
+        let array_ptr = self.array.clone();
+        run_with_obs!(self.runtime, (array_ptr), move || unsafe {
+            libobs::obs_data_array_erase(array_ptr, index)
+        })
+    }
+}
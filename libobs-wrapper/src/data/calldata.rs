@@ -0,0 +1,270 @@
+use std::ffi::{c_void, CStr};
+
+use libobs::calldata_t;
+
+use crate::{
+    run_with_obs,
+    runtime::ObsRuntime,
+    sources::ObsSourceRef,
+    unsafe_send::Sendable,
+    utils::{ObsError, ObsString},
+};
+
+/// An owned `calldata_t`, which holds the parameters of signals and procedures.
+///
+/// Values are looked up by name. A getter returns `None` if the value does not exist or if its
+/// size differs from the requested type. The type itself is not stored, so reading a value as
+/// another type of the same size (e.g. a pointer as `i64`) succeeds and returns its raw bits.
+/// The memory of the calldata is freed when it is dropped.
+///
+/// ```no_run
+/// use libobs_wrapper::data::CallData;
+///
+/// let mut calldata = CallData::new();
+/// calldata.set_string("path", "C:/recording.mp4");
+/// calldata.set_int("index", 1);
+///
+/// assert_eq!(calldata.get_int("index"), Some(1));
+/// ```
+pub struct CallData {
+    data: calldata_t,
+}
+
+// The stack of the calldata is owned by this struct and only read through `&self`
+unsafe impl Send for CallData {}
+unsafe impl Sync for CallData {}
+
+impl CallData {
+    /// Creates an empty calldata.
+    pub fn new() -> Self {
+        Self {
+            data: unsafe { std::mem::zeroed() },
+        }
+    }
+
+    /// Copies the given calldata, so the copy can outlive the signal or procedure call.
+    ///
+    /// # Safety
+    /// `calldata` must be null or point to a valid `calldata_t`.
+    pub(crate) unsafe fn from_raw(calldata: *const calldata_t) -> Self {
+        let mut data: calldata_t = std::mem::zeroed();
+        if calldata.is_null() || (*calldata).stack.is_null() || (*calldata).size == 0 {
+            return Self { data };
+        }
+
+        // Strings are stored inline on the stack, so copying it copies the whole calldata
+        let size = (*calldata).size;
+        let stack = libobs::bmalloc(size) as *mut u8;
+        std::ptr::copy_nonoverlapping((*calldata).stack, stack, size);
+
+        data.stack = stack;
+        data.size = size;
+        data.capacity = size;
+        Self { data }
+    }
+
+    /// Returns a pointer to the raw `calldata_t`.
+    pub fn as_ptr(&self) -> *const calldata_t {
+        &self.data
+    }
+
+    /// Returns a mutable pointer to the raw `calldata_t`, which can be passed to libobs
+    /// functions that write to the calldata.
+    pub fn as_mut_ptr(&mut self) -> *mut calldata_t {
+        &mut self.data
+    }
+
+    fn set_data<T: Copy>(&mut self, name: &str, value: T) {
+        let name = ObsString::new(name);
+        unsafe {
+            libobs::calldata_set_data(
+                &mut self.data,
+                name.as_ptr().0,
+                &value as *const T as *const c_void,
+                std::mem::size_of::<T>(),
+            )
+        }
+    }
+
+    pub fn set_int(&mut self, name: &str, value: i64) {
+        self.set_data(name, value);
+    }
+
+    pub fn set_float(&mut self, name: &str, value: f64) {
+        self.set_data(name, value);
+    }
+
+    pub fn set_bool(&mut self, name: &str, value: bool) {
+        self.set_data(name, value);
+    }
+
+    pub fn set_ptr(&mut self, name: &str, value: Sendable<*mut c_void>) {
+        self.set_data(name, value.0);
+    }
+
+    pub fn set_string(&mut self, name: &str, value: &str) {
+        let name = ObsString::new(name);
+        let value = ObsString::new(value);
+        let value_ptr = value.as_ptr().0;
+
+        // Strings are stored including their null terminator
+        let len = unsafe { CStr::from_ptr(value_ptr) }
+            .to_bytes_with_nul()
+            .len();
+        unsafe {
+            libobs::calldata_set_data(
+                &mut self.data,
+                name.as_ptr().0,
+                value_ptr as *const c_void,
+                len,
+            )
+        }
+    }
+
+    fn get_data<T: Copy>(&self, name: &str) -> Option<T> {
+        let name = ObsString::new(name);
+        let mut value = std::mem::MaybeUninit::<T>::zeroed();
+
+        let success = unsafe {
+            libobs::calldata_get_data(
+                &self.data,
+                name.as_ptr().0,
+                value.as_mut_ptr() as *mut c_void,
+                std::mem::size_of::<T>(),
+            )
+        };
+
+        // calldata_get_data fails if the stored value does not have the same size
+        success.then(|| unsafe { value.assume_init() })
+    }
+
+    /// Returns the 8 byte value stored under the given name as integer. Only the size of the
+    /// value is checked, not its type.
+    pub fn get_int(&self, name: &str) -> Option<i64> {
+        self.get_data::<i64>(name)
+    }
+
+    /// Returns the 8 byte value stored under the given name as float. Only the size of the
+    /// value is checked, not its type.
+    pub fn get_float(&self, name: &str) -> Option<f64> {
+        self.get_data::<f64>(name)
+    }
+
+    /// Returns the 1 byte value stored under the given name as bool. Only the size of the
+    /// value is checked, not its type.
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        self.get_data::<u8>(name).map(|b| b != 0)
+    }
+
+    pub fn get_string(&self, name: &str) -> Option<String> {
+        let name = ObsString::new(name);
+        let mut value = std::ptr::null();
+
+        let success =
+            unsafe { libobs::calldata_get_string(&self.data, name.as_ptr().0, &mut value) };
+
+        if !success || value.is_null() {
+            return None;
+        }
+
+        Some(
+            unsafe { CStr::from_ptr(value) }
+                .to_string_lossy()
+                .to_string(),
+        )
+    }
+
+    /// Returns the pointer stored under the given name. Null pointers are returned as `None`.
+    /// Only the size of the value is checked, not its type.
+    pub fn get_ptr(&self, name: &str) -> Option<Sendable<*mut c_void>> {
+        self.get_data::<*mut c_void>(name)
+            .filter(|ptr| !ptr.is_null())
+            .map(Sendable)
+    }
+
+    /// Returns the source stored under the given name as `ObsSourceRef`, which holds its own
+    /// reference to the source.
+    ///
+    /// # Safety
+    ///
+    /// The calldata only stores the raw pointer of the source, which is only guaranteed to be
+    /// valid while the signal is being emitted. The caller must ensure the source has not been
+    /// freed since, e.g. by holding another reference to it. This must not be used for signals
+    /// like `source_destroy`.
+    pub unsafe fn get_source(
+        &self,
+        name: &str,
+        runtime: ObsRuntime,
+    ) -> Result<Option<ObsSourceRef>, ObsError> {
+        let ptr = match self.get_ptr(name) {
+            Some(ptr) => Sendable(ptr.0 as *mut libobs::obs_source_t),
+            None => return Ok(None),
+        };
+
+        let source = run_with_obs!(runtime, (ptr), move || unsafe {
+            Sendable(libobs::obs_source_get_ref(ptr))
+        })?;
+
+        if source.0.is_null() {
+            return Ok(None);
+        }
+
+        ObsSourceRef::from_raw(source, runtime).map(Some)
+    }
+}
+
+impl Default for CallData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Calls the procedure with the given name of the given proc handler and returns the calldata
+/// with the values the procedure has written.
+pub(crate) fn call_proc(
+    handler: Sendable<*mut libobs::proc_handler_t>,
+    name: &str,
+    mut calldata: CallData,
+    runtime: &ObsRuntime,
+) -> Result<CallData, ObsError> {
+    if handler.0.is_null() {
+        return Err(ObsError::NullPointer);
+    }
+
+    let proc_name = ObsString::new(name);
+    let name_ptr = proc_name.as_ptr();
+    let calldata_ptr = Sendable(calldata.as_mut_ptr());
+
+    // The call blocks until the procedure returned, so the calldata outlives the pointer
+    let success = run_with_obs!(runtime, (handler, name_ptr, calldata_ptr), move || unsafe {
+        libobs::proc_handler_call(handler, name_ptr, calldata_ptr)
+    })?;
+
+    if !success {
+        return Err(ObsError::ProcCallFailure(name.to_string()));
+    }
+
+    Ok(calldata)
+}
+
+impl Clone for CallData {
+    fn clone(&self) -> Self {
+        unsafe { Self::from_raw(&self.data) }
+    }
+}
+
+impl std::fmt::Debug for CallData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CallData")
+            .field("size", &self.data.size)
+            .finish()
+    }
+}
+
+impl Drop for CallData {
+    fn drop(&mut self) {
+        if !self.data.fixed && !self.data.stack.is_null() {
+            unsafe { libobs::bfree(self.data.stack as *mut c_void) };
+        }
+    }
+}
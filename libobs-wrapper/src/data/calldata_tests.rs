@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use super::super::CallData;
+
+    #[test]
+    fn test_calldata_roundtrip() {
+        let mut calldata = CallData::new();
+        calldata.set_int("int", 42);
+        calldata.set_float("float", 1.5);
+        calldata.set_bool("bool", true);
+        calldata.set_string("string", "hello");
+
+        assert_eq!(calldata.get_int("int"), Some(42));
+        assert_eq!(calldata.get_float("float"), Some(1.5));
+        assert_eq!(calldata.get_bool("bool"), Some(true));
+        assert_eq!(calldata.get_string("string"), Some("hello".to_string()));
+    }
+
+    #[test]
+    fn test_calldata_missing_and_mismatched_values() {
+        let mut calldata = CallData::new();
+        calldata.set_bool("bool", false);
+
+        assert_eq!(calldata.get_int("missing"), None);
+        assert_eq!(calldata.get_int("bool"), None);
+        assert!(calldata.get_ptr("bool").is_none());
+        assert_eq!(calldata.get_bool("bool"), Some(false));
+    }
+
+    #[test]
+    fn test_calldata_overwrite_and_clone() {
+        let mut calldata = CallData::new();
+        calldata.set_string("path", "short");
+        calldata.set_string("path", "a much longer path than before");
+        calldata.set_int("index", 1);
+
+        let copy = calldata.clone();
+        drop(calldata);
+
+        assert_eq!(
+            copy.get_string("path"),
+            Some("a much longer path than before".to_string())
+        );
+        assert_eq!(copy.get_int("index"), Some(1));
+    }
+}
@@ -13,7 +13,7 @@ use super::{ObsData, _ObsDataDropGuard};
 /// This should not be updated directly using the pointer, but instead through the corresponding update methods on the holder of this data.
 pub struct ImmutableObsData {
     ptr: Sendable<*mut obs_data_t>,
-    runtime: ObsRuntime,
+    pub(crate) runtime: ObsRuntime,
     _drop_guard: Arc<_ObsDataDropGuard>,
 }
 
@@ -4,22 +4,40 @@ use std::{
 };
 
 use crate::{
+    enums::{ObsDataNumberType, ObsDataType},
     impl_obs_drop, run_with_obs,
     runtime::ObsRuntime,
     unsafe_send::Sendable,
     utils::{ObsError, ObsString},
 };
 use libobs::obs_data;
+use num_traits::FromPrimitive;
 
+mod array;
+pub use array::*;
+mod calldata;
+pub use calldata::*;
 pub mod audio;
 pub mod immutable;
 mod lib_support;
 pub mod output;
 pub mod properties;
+mod serde_bridge;
 pub mod video;
 pub use lib_support::*;
 mod updater;
 pub use updater::*;
+mod value;
+pub use value::*;
+
+#[cfg(test)]
+mod calldata_tests;
+
+#[cfg(test)]
+mod serde_bridge_tests;
+
+#[cfg(test)]
+mod value_tests;
 
 #[derive(Debug)]
 pub(crate) struct _ObsDataDropGuard {
@@ -62,6 +80,16 @@ impl ObsData {
         })
     }
 
+    /// Wraps the given `obs_data` pointer. This takes ownership of one reference of the data,
+    /// so the data is released when this wrapper is dropped.
+    pub(crate) fn from_raw(obs_data: Sendable<*mut obs_data>, runtime: ObsRuntime) -> Self {
+        ObsData {
+            obs_data: obs_data.clone(),
+            runtime: runtime.clone(),
+            _drop_guard: Arc::new(_ObsDataDropGuard { obs_data, runtime }),
+        }
+    }
+
     pub fn bulk_update(&mut self) -> ObsDataUpdater {
         ObsDataUpdater {
             changes: Vec::new(),
@@ -76,6 +104,11 @@ impl ObsData {
         self.obs_data.clone()
     }
 
+    /// Returns the runtime this data was created with, e.g. to create nested objects.
+    pub fn runtime(&self) -> &ObsRuntime {
+        &self.runtime
+    }
+
     /// Sets a string in `obs_data` and stores it so
     /// it in `ObsData` does not get freed.
     pub fn set_string<T: Into<ObsString> + Send + Sync, K: Into<ObsString> + Send + Sync>(
@@ -257,6 +290,421 @@ impl ObsData {
         Ok(result)
     }
 
+    /// Sets a nested object in `obs_data`. The object is referenced, not copied.
+    pub fn set_obj<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: &ObsData,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+        let value_ptr = value.as_ptr();
+
+        run_with_obs!(
+            self.runtime,
+            (key_ptr, data_ptr, value_ptr),
+            move || unsafe {
+                libobs::obs_data_set_obj(data_ptr, key_ptr, value_ptr);
+            }
+        )?;
+
+        Ok(self)
+    }
+
+    /// Returns the nested object with the given key. Changes to the returned object are
+    /// reflected in this `ObsData`.
+    pub fn get_obj<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<ObsData>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        let result = run_with_obs!(self.runtime, (key_ptr, data_ptr), move || unsafe {
+            Sendable(libobs::obs_data_get_obj(data_ptr, key_ptr))
+        })?;
+
+        if result.0.is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(ObsData::from_raw(result, self.runtime.clone())))
+    }
+
+    /// Sets an array in `obs_data`. The array is referenced, not copied.
+    pub fn set_array<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: &ObsDataArray,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+        let value_ptr = value.as_ptr();
+
+        run_with_obs!(
+            self.runtime,
+            (key_ptr, data_ptr, value_ptr),
+            move || unsafe {
+                libobs::obs_data_set_array(data_ptr, key_ptr, value_ptr);
+            }
+        )?;
+
+        Ok(self)
+    }
+
+    /// Returns the array with the given key. Changes to the returned array are
+    /// reflected in this `ObsData`.
+    pub fn get_array<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<ObsDataArray>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        let result = run_with_obs!(self.runtime, (key_ptr, data_ptr), move || unsafe {
+            Sendable(libobs::obs_data_get_array(data_ptr, key_ptr))
+        })?;
+
+        if result.0.is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(ObsDataArray::from_raw(result, self.runtime.clone())))
+    }
+
+    /// Sets the default value of a string. The default is used when no user value is set.
+    pub fn set_default_string<
+        T: Into<ObsString> + Send + Sync,
+        K: Into<ObsString> + Send + Sync,
+    >(
+        &mut self,
+        key: T,
+        value: K,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+        let value = value.into();
+
+        let key_ptr = key.as_ptr();
+        let value_ptr = value.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(
+            self.runtime,
+            (data_ptr, key_ptr, value_ptr),
+            move || unsafe { libobs::obs_data_set_default_string(data_ptr, key_ptr, value_ptr) }
+        )?;
+
+        Ok(self)
+    }
+
+    /// Sets the default value of an int. The default is used when no user value is set.
+    pub fn set_default_int<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: i64,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (key_ptr, data_ptr), move || unsafe {
+            libobs::obs_data_set_default_int(data_ptr, key_ptr, value);
+        })?;
+
+        Ok(self)
+    }
+
+    /// Sets the default value of a bool. The default is used when no user value is set.
+    pub fn set_default_bool<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: bool,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (key_ptr, data_ptr), move || unsafe {
+            libobs::obs_data_set_default_bool(data_ptr, key_ptr, value);
+        })?;
+
+        Ok(self)
+    }
+
+    /// Sets the default value of a double. The default is used when no user value is set.
+    pub fn set_default_double<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: f64,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (key_ptr, data_ptr), move || unsafe {
+            libobs::obs_data_set_default_double(data_ptr, key_ptr, value);
+        })?;
+
+        Ok(self)
+    }
+
+    /// Sets the default value of a nested object. The default is used when no user value is set.
+    pub fn set_default_obj<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: &ObsData,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+        let value_ptr = value.as_ptr();
+
+        run_with_obs!(
+            self.runtime,
+            (key_ptr, data_ptr, value_ptr),
+            move || unsafe {
+                libobs::obs_data_set_default_obj(data_ptr, key_ptr, value_ptr);
+            }
+        )?;
+
+        Ok(self)
+    }
+
+    /// Sets the default value of an array. The default is used when no user value is set.
+    pub fn set_default_array<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: &ObsDataArray,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+        let value_ptr = value.as_ptr();
+
+        run_with_obs!(
+            self.runtime,
+            (key_ptr, data_ptr, value_ptr),
+            move || unsafe {
+                libobs::obs_data_set_default_array(data_ptr, key_ptr, value_ptr);
+            }
+        )?;
+
+        Ok(self)
+    }
+
+    /// Returns the default string of the given key, ignoring any user value.
+    pub fn get_default_string<T: Into<ObsString> + Send + Sync>(
+        &self,
+        key: T,
+    ) -> Result<Option<String>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        let result = run_with_obs!(self.runtime, (data_ptr, key_ptr), move || unsafe {
+            if libobs::obs_data_has_default_value(data_ptr, key_ptr) {
+                Some(Sendable(libobs::obs_data_get_default_string(
+                    data_ptr, key_ptr,
+                )))
+            } else {
+                None
+            }
+        })?;
+
+        let result = match result {
+            Some(result) => result,
+            None => return Ok(None),
+        };
+
+        if result.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let result = unsafe { CStr::from_ptr(result.0) };
+        let result = result
+            .to_str()
+            .map_err(|_| ObsError::StringConversionError)?;
+
+        Ok(Some(result.to_string()))
+    }
+
+    /// Returns the default int of the given key, ignoring any user value.
+    pub fn get_default_int<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<i64>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (data_ptr, key_ptr), move || unsafe {
+            if libobs::obs_data_has_default_value(data_ptr, key_ptr) {
+                Some(libobs::obs_data_get_default_int(data_ptr, key_ptr))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the default bool of the given key, ignoring any user value.
+    pub fn get_default_bool<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<bool>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (data_ptr, key_ptr), move || unsafe {
+            if libobs::obs_data_has_default_value(data_ptr, key_ptr) {
+                Some(libobs::obs_data_get_default_bool(data_ptr, key_ptr))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the default double of the given key, ignoring any user value.
+    pub fn get_default_double<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<f64>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (data_ptr, key_ptr), move || unsafe {
+            if libobs::obs_data_has_default_value(data_ptr, key_ptr) {
+                Some(libobs::obs_data_get_default_double(data_ptr, key_ptr))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns true if a user value is set for the given key.
+    pub fn has_user_value<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<bool, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (data_ptr, key_ptr), move || unsafe {
+            libobs::obs_data_has_user_value(data_ptr, key_ptr)
+        })
+    }
+
+    /// Returns true if a default value is set for the given key.
+    pub fn has_default_value<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<bool, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (data_ptr, key_ptr), move || unsafe {
+            libobs::obs_data_has_default_value(data_ptr, key_ptr)
+        })
+    }
+
+    /// Removes the user value of the given key. The default value is kept.
+    pub fn erase<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (data_ptr, key_ptr), move || unsafe {
+            libobs::obs_data_unset_user_value(data_ptr, key_ptr);
+        })?;
+
+        Ok(self)
+    }
+
+    /// Removes the given key completely, including its default value.
+    pub fn remove_with_default<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (data_ptr, key_ptr), move || unsafe {
+            libobs::obs_data_erase(data_ptr, key_ptr);
+        })?;
+
+        Ok(self)
+    }
+
+    /// Returns information about all items stored in this `ObsData`, including items that only
+    /// have a default value.
+    pub fn items(&self) -> Result<Vec<ObsDataItem>, ObsError> {
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (data_ptr), move || unsafe {
+            let mut result = Vec::new();
+            let mut item = libobs::obs_data_first(data_ptr);
+            while !item.is_null() {
+                let name = libobs::obs_data_item_get_name(item);
+                let name = if name.is_null() {
+                    String::new()
+                } else {
+                    CStr::from_ptr(name).to_string_lossy().to_string()
+                };
+
+                let data_type = ObsDataType::from_i32(libobs::obs_data_item_gettype(item))
+                    .unwrap_or(ObsDataType::Null);
+                let number_type = ObsDataNumberType::from_i32(libobs::obs_data_item_numtype(item))
+                    .unwrap_or(ObsDataNumberType::Invalid);
+
+                result.push(ObsDataItem {
+                    name,
+                    data_type,
+                    number_type,
+                    has_user_value: libobs::obs_data_item_has_user_value(item),
+                    has_default_value: libobs::obs_data_item_has_default_value(item),
+                });
+
+                // Releases the current item and moves to the next one
+                libobs::obs_data_item_next(&mut item);
+            }
+
+            result
+        })
+    }
+
+    /// Returns the keys of all items stored in this `ObsData`.
+    pub fn keys(&self) -> Result<Vec<String>, ObsError> {
+        Ok(self.items()?.into_iter().map(|item| item.name).collect())
+    }
+
     pub fn from_json(json: &str, runtime: ObsRuntime) -> Result<Self, ObsError> {
         let cstr = CString::new(json).map_err(|_| ObsError::JsonParseError)?;
 
@@ -296,6 +744,17 @@ impl ObsData {
     }
 }
 
+/// Information about a single item of an `ObsData` object.
+#[derive(Debug, Clone, PartialEq, Eq, getters0::Getters)]
+#[skip_new]
+pub struct ObsDataItem {
+    name: String,
+    data_type: ObsDataType,
+    number_type: ObsDataNumberType,
+    has_user_value: bool,
+    has_default_value: bool,
+}
+
 impl_obs_drop!(_ObsDataDropGuard, (obs_data), move || unsafe {
     libobs::obs_data_release(obs_data)
 });
@@ -16,7 +16,7 @@ use crate::{
     utils::{ObsError, ObsString},
 };
 
-use super::ObsData;
+use super::{call_proc, CallData, ObsData};
 
 mod replay_buffer;
 pub use replay_buffer::*;
@@ -352,6 +352,11 @@ impl ObsOutputRef {
             Sendable(libobs::obs_output_get_last_error(output_ptr))
         })?;
 
+        // Outputs like the virtual camera may fail without setting an error message
+        if err.0.is_null() {
+            return Err(ObsError::OutputStartFailure(None));
+        }
+
         let c_str = unsafe { CStr::from_ptr(err.0) };
         let err_str = c_str.to_str().ok().map(|x| x.to_string());
 
@@ -456,6 +461,17 @@ impl ObsOutputRef {
     pub fn as_ptr(&self) -> Sendable<*mut obs_output> {
         self.output.clone()
     }
+
+    /// Calls the procedure with the given name of this output (for example `get_last_replay`
+    /// of the replay buffer) and returns the calldata the procedure has written to.
+    pub fn call_proc(&self, name: &str, calldata: CallData) -> Result<CallData, ObsError> {
+        let output_ptr = self.output.clone();
+        let handler = run_with_obs!(self.runtime, (output_ptr), move || unsafe {
+            Sendable(libobs::obs_output_get_proc_handler(output_ptr))
+        })?;
+
+        call_proc(handler, name, calldata, &self.runtime)
+    }
 }
 
 impl_signal_manager!(|ptr| unsafe { libobs::obs_output_get_signal_handler(ptr) }, ObsOutputSignals for ObsOutputRef<*mut libobs::obs_output>, [
@@ -3,18 +3,9 @@
 //! This module extends the ObsOutputRef to provide replay buffer capabilities.
 //! A replay buffer is a special type of output that continuously records
 //! the last N seconds of content, allowing the user to save this buffer on demand. This must be configured. More documentation soon.
-use std::{
-    ffi::c_char,
-    mem::MaybeUninit,
-    path::{Path, PathBuf},
-};
+use std::path::{Path, PathBuf};
 
-use libobs::calldata_t;
-
-use crate::{
-    run_with_obs,
-    utils::{ObsError, ObsString},
-};
+use crate::{data::CallData, utils::ObsError};
 
 use super::ObsOutputRef;
 
@@ -57,62 +48,20 @@ impl ReplayBufferOutput for ObsOutputRef {
     ///   - Failure to call "get_last_replay" procedure
     ///   - Failure to extract the path from calldata
     fn save_buffer(&self) -> Result<Box<Path>, ObsError> {
-        let output_ptr = self.output.clone();
-
-        let path = run_with_obs!(self.runtime, (output_ptr), move || {
-            let ph = unsafe { libobs::obs_output_get_proc_handler(output_ptr) };
-            if ph.is_null() {
-                return Err(ObsError::OutputSaveBufferFailure(
-                    "Failed to get proc handler.".to_string(),
-                ));
-            }
-
-            let name = ObsString::new("save");
-            let call_success = unsafe {
-                let mut calldata = MaybeUninit::<calldata_t>::zeroed();
-                libobs::proc_handler_call(ph, name.as_ptr().0, calldata.as_mut_ptr())
-            };
-
-            if !call_success {
-                return Err(ObsError::OutputSaveBufferFailure(
-                    "Failed to call proc handler.".to_string(),
-                ));
-            }
-
-            let func_get = ObsString::new("get_last_replay");
-            let last_replay = unsafe {
-                let mut calldata = MaybeUninit::<calldata_t>::zeroed();
-                let success =
-                    libobs::proc_handler_call(ph, func_get.as_ptr().0, calldata.as_mut_ptr());
-
-                if !success {
-                    return Err(ObsError::OutputSaveBufferFailure(
-                        "Failed to call get_last_replay.".to_string(),
-                    ));
-                }
-
-                calldata.assume_init()
-            };
-
-            let path_get = ObsString::new("path");
-
-            let mut s = MaybeUninit::<*const c_char>::uninit();
-
-            let res = unsafe {
-                libobs::calldata_get_string(&last_replay, path_get.as_ptr().0, s.as_mut_ptr())
-            };
-            if !res {
-                return Err(ObsError::OutputSaveBufferFailure(
-                    "Failed to get path from last replay.".to_string(),
-                ));
-            }
+        self.call_proc("save", CallData::new()).map_err(|e| {
+            ObsError::OutputSaveBufferFailure(format!("Failed to call save: {}", e))
+        })?;
 
-            let s: *const c_char = unsafe { s.assume_init() };
-            let path = unsafe { std::ffi::CStr::from_ptr(s) }.to_str().unwrap();
+        let last_replay = self
+            .call_proc("get_last_replay", CallData::new())
+            .map_err(|e| {
+                ObsError::OutputSaveBufferFailure(format!("Failed to call get_last_replay: {}", e))
+            })?;
 
-            Ok(PathBuf::from(path))
-        })??;
+        let path = last_replay.get_string("path").ok_or_else(|| {
+            ObsError::OutputSaveBufferFailure("Failed to get path from last replay.".to_string())
+        })?;
 
-        Ok(path.into_boxed_path())
+        Ok(PathBuf::from(path).into_boxed_path())
     }
 }
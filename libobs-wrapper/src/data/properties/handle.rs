@@ -0,0 +1,245 @@
+//! A live handle to an `obs_properties_t` object.
+//!
+//! OBS sources often change the visibility or the content of properties when another property
+//! changes (for example the window list of a window capture depends on the selected capture mode).
+//! These changes are done by the modified callbacks of the properties, which need the
+//! `obs_properties_t` to stay alive. This module provides the `ObsPropertiesHandle` which keeps
+//! the properties alive and re-reads them after the callbacks ran, so a settings UI can be updated
+//! the same way OBS Studio does it.
+
+use std::collections::HashMap;
+
+use libobs::obs_properties;
+
+use crate::{
+    data::{apply_change_raw, ObsData, ObsDataChange},
+    impl_obs_drop, run_with_obs,
+    runtime::ObsRuntime,
+    sources::ObsSourceRef,
+    unsafe_send::Sendable,
+    utils::{ObsError, ObsString},
+};
+
+use super::{collect_properties, types::button_clicked_raw, ObsProperty};
+
+/// Describes which properties changed between two snapshots of the same `obs_properties_t`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObsPropertiesDiff {
+    /// Names of the properties that were added
+    pub added: Vec<String>,
+    /// Names of the properties that were removed
+    pub removed: Vec<String>,
+    /// Names of the properties whose content changed (list items, limits, visibility, etc.)
+    pub changed: Vec<String>,
+}
+
+impl ObsPropertiesDiff {
+    /// Computes the difference between the `old` and the `new` set of properties.
+    /// The names in each list are sorted alphabetically.
+    pub fn between(old: &HashMap<String, ObsProperty>, new: &HashMap<String, ObsProperty>) -> Self {
+        let mut diff = Self::default();
+        for (name, property) in new {
+            match old.get(name) {
+                None => diff.added.push(name.clone()),
+                Some(old_property) if old_property != property => diff.changed.push(name.clone()),
+                Some(_) => {}
+            }
+        }
+
+        diff.removed = old
+            .keys()
+            .filter(|name| !new.contains_key(*name))
+            .cloned()
+            .collect();
+
+        diff.added.sort_unstable();
+        diff.removed.sort_unstable();
+        diff.changed.sort_unstable();
+        diff
+    }
+
+    /// Returns true if no property was added, removed or changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// The result of running modified callbacks on an `ObsPropertiesHandle`.
+#[derive(Debug, Clone)]
+pub struct ObsPropertiesRefresh {
+    /// Whether the modified callback requested the properties to be rebuilt
+    pub needs_refresh: bool,
+    /// The refreshed set of properties
+    pub properties: HashMap<String, ObsProperty>,
+    /// The difference to the properties before the callback ran
+    pub diff: ObsPropertiesDiff,
+}
+
+/// Keeps an `obs_properties_t` alive so modified callbacks can be run on it.
+///
+/// Created through `ObsPropertyObject::get_properties_handle` or
+/// `ObsPropertyObject::get_properties_handle_by_id`. The properties are destroyed when this
+/// handle is dropped.
+#[derive(Debug)]
+pub struct ObsPropertiesHandle {
+    properties: Sendable<*mut obs_properties>,
+    snapshot: HashMap<String, ObsProperty>,
+    pub(crate) runtime: ObsRuntime,
+}
+
+impl ObsPropertiesHandle {
+    pub(crate) fn new(
+        properties: Sendable<*mut obs_properties>,
+        runtime: ObsRuntime,
+    ) -> Result<Self, ObsError> {
+        if properties.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let properties_ptr = properties.clone();
+        let snapshot = run_with_obs!(runtime, (properties_ptr), move || unsafe {
+            collect_properties(properties_ptr)
+        })?;
+
+        Ok(Self {
+            properties,
+            snapshot,
+            runtime,
+        })
+    }
+
+    /// Returns the properties as they were read after the last refresh.
+    pub fn properties(&self) -> &HashMap<String, ObsProperty> {
+        &self.snapshot
+    }
+
+    /// Returns the property with the given name as it was read after the last refresh.
+    pub fn get(&self, name: &str) -> Option<&ObsProperty> {
+        self.snapshot.get(name)
+    }
+
+    /// Re-reads the properties without running any callbacks.
+    pub fn refresh(&mut self) -> Result<ObsPropertiesRefresh, ObsError> {
+        self.refresh_inner(false)
+    }
+
+    /// Runs the modified callbacks of all properties with the given settings
+    /// (`obs_properties_apply_settings`) and returns the refreshed properties.
+    ///
+    /// This should be called once after the handle was created so the properties match the
+    /// current settings of the object.
+    pub fn apply_settings(&mut self, settings: &ObsData) -> Result<ObsPropertiesRefresh, ObsError> {
+        let properties_ptr = self.properties.clone();
+        let settings_ptr = settings.as_ptr();
+
+        run_with_obs!(
+            self.runtime,
+            (properties_ptr, settings_ptr),
+            move || unsafe {
+                libobs::obs_properties_apply_settings(properties_ptr, settings_ptr);
+            }
+        )?;
+
+        self.refresh_inner(false)
+    }
+
+    /// Runs the modified callback of the property with the given name. The new value of the
+    /// property must already be written to `settings`.
+    pub fn property_modified<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        name: T,
+        settings: &ObsData,
+    ) -> Result<ObsPropertiesRefresh, ObsError> {
+        let name: ObsString = name.into();
+        let name_ptr = name.as_ptr();
+        let properties_ptr = self.properties.clone();
+        let settings_ptr = settings.as_ptr();
+
+        let needs_refresh = run_with_obs!(
+            self.runtime,
+            (properties_ptr, settings_ptr, name_ptr),
+            move || unsafe {
+                let property = libobs::obs_properties_get(properties_ptr, name_ptr);
+                if property.is_null() {
+                    return None;
+                }
+
+                Some(libobs::obs_property_modified(property, settings_ptr))
+            }
+        )?
+        .ok_or(ObsError::PropertyNotFound)?;
+
+        self.refresh_inner(needs_refresh)
+    }
+
+    /// Invokes the callback of the button with the given name on `source` and returns the
+    /// refreshed properties.
+    pub fn click_button<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        name: T,
+        source: &ObsSourceRef,
+    ) -> Result<ObsPropertiesRefresh, ObsError> {
+        let name: ObsString = name.into();
+        let name_ptr = name.as_ptr();
+        let properties_ptr = self.properties.clone();
+        let source_ptr = source.source.clone();
+
+        let needs_refresh = run_with_obs!(
+            self.runtime,
+            (properties_ptr, source_ptr, name_ptr),
+            move || unsafe { button_clicked_raw(properties_ptr, name_ptr, source_ptr as _) }
+        )?
+        .ok_or(ObsError::PropertyNotFound)?;
+
+        self.refresh_inner(needs_refresh)
+    }
+
+    /// Writes the given change to `settings` and runs the modified callback of the changed
+    /// property afterwards.
+    ///
+    /// Note that this does not update the object the properties belong to. Use the updater of
+    /// the object (or `ObsUpdatable::update_raw`) to apply `settings` to it.
+    pub fn apply_change(
+        &mut self,
+        settings: &mut ObsData,
+        change: ObsDataChange,
+    ) -> Result<ObsPropertiesRefresh, ObsError> {
+        let key = change.key().clone();
+        let settings_ptr = settings.as_ptr();
+
+        let change = run_with_obs!(self.runtime, (settings_ptr), move || unsafe {
+            apply_change_raw(settings_ptr, &change);
+
+            // Objects and arrays must not be dropped on the OBS thread
+            change
+        })?;
+        drop(change);
+
+        self.property_modified(key, settings)
+    }
+
+    fn refresh_inner(&mut self, needs_refresh: bool) -> Result<ObsPropertiesRefresh, ObsError> {
+        let properties_ptr = self.properties.clone();
+        let properties = run_with_obs!(self.runtime, (properties_ptr), move || unsafe {
+            collect_properties(properties_ptr)
+        })?;
+
+        let diff = ObsPropertiesDiff::between(&self.snapshot, &properties);
+        self.snapshot = properties.clone();
+
+        Ok(ObsPropertiesRefresh {
+            needs_refresh: needs_refresh || !diff.is_empty(),
+            properties,
+            diff,
+        })
+    }
+
+    /// Returns the raw `obs_properties_t` pointer, which is only valid while the handle is alive.
+    pub fn as_ptr(&self) -> Sendable<*mut obs_properties> {
+        self.properties.clone()
+    }
+}
+
+impl_obs_drop!(ObsPropertiesHandle, (properties), move || unsafe {
+    libobs::obs_properties_destroy(properties);
+});
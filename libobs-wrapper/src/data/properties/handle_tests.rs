@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::super::{types::ObsBoolProperty, ObsPropertiesDiff, ObsProperty};
+
+    fn properties(entries: &[(&str, ObsProperty)]) -> HashMap<String, ObsProperty> {
+        entries
+            .iter()
+            .map(|(name, property)| (name.to_string(), property.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_empty() {
+        let old = properties(&[("a", ObsProperty::Invalid)]);
+        let diff = ObsPropertiesDiff::between(&old, &old.clone());
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_added_and_removed() {
+        let old = properties(&[("a", ObsProperty::Invalid), ("b", ObsProperty::Invalid)]);
+        let new = properties(&[("b", ObsProperty::Invalid), ("c", ObsProperty::Invalid)]);
+
+        let diff = ObsPropertiesDiff::between(&old, &new);
+        assert_eq!(diff.added, vec!["c".to_string()]);
+        assert_eq!(diff.removed, vec!["a".to_string()]);
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_changed() {
+        let old = properties(&[("a", ObsProperty::Invalid), ("b", ObsProperty::Invalid)]);
+        let new = properties(&[
+            ("a", ObsProperty::Bool(ObsBoolProperty::new_for_tests("a"))),
+            ("b", ObsProperty::Invalid),
+        ]);
+
+        let diff = ObsPropertiesDiff::between(&old, &new);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.changed, vec!["a".to_string()]);
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_sorted() {
+        let old = properties(&[]);
+        let new = properties(&[
+            ("c", ObsProperty::Invalid),
+            ("a", ObsProperty::Invalid),
+            ("b", ObsProperty::Invalid),
+        ]);
+
+        let diff = ObsPropertiesDiff::between(&old, &new);
+        assert_eq!(diff.added, vec!["a", "b", "c"]);
+    }
+}
@@ -18,22 +18,47 @@ macro_rules! assert_type {
 macro_rules! impl_general_property {
     ($type: ident) => {
         paste::paste! {
-            #[derive(Debug, getters0::Getters, Clone)]
+            #[derive(Debug, getters0::Getters, Clone, PartialEq)]
             #[skip_new]
             pub struct [<Obs $type Property>] {
                 name: String,
-                description: Option<String>
+                description: Option<String>,
+                long_description: Option<String>,
+                visible: bool,
+                enabled: bool,
             }
             impl From<crate::data::properties::PropertyCreationInfo> for [<Obs $type Property>] {
                 fn from(
                     crate::data::properties::PropertyCreationInfo {
                         name,
                         description,
+                        long_description,
+                        visible,
+                        enabled,
                         pointer,
                     }: crate::data::properties::PropertyCreationInfo,
                 ) -> Self {
                     crate::data::properties::assert_type!($type, pointer);
-                    Self { name, description }
+                    Self {
+                        name,
+                        description,
+                        long_description,
+                        visible,
+                        enabled,
+                    }
+                }
+            }
+
+            #[cfg(test)]
+            impl [<Obs $type Property>] {
+                pub(crate) fn new_for_tests(name: &str) -> Self {
+                    Self {
+                        name: name.to_string(),
+                        description: None,
+                        long_description: None,
+                        visible: true,
+                        enabled: true,
+                    }
                 }
             }
         }
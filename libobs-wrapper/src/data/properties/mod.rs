@@ -1,14 +1,19 @@
 mod enums;
+mod handle;
 mod macros;
 pub mod prop_impl;
 pub mod types;
 
+#[cfg(test)]
+mod handle_tests;
+
 use std::{collections::HashMap, ffi::CStr};
 
 use libobs::obs_properties;
 use macros::*;
 
 pub use enums::*;
+pub use handle::*;
 use num_traits::FromPrimitive;
 use types::*;
 
@@ -19,12 +24,12 @@ use crate::{
     utils::{ObsError, ObsString},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ObsProperty {
     /// A property that is not valid
     Invalid,
     /// A boolean property
-    Bool,
+    Bool(ObsBoolProperty),
     /// An integer property
     Int(ObsNumberProperty<i32>),
     /// A float property
@@ -57,6 +62,12 @@ pub trait ObsPropertyObjectPrivate {
         id: T,
         runtime: ObsRuntime,
     ) -> Result<Sendable<*mut libobs::obs_properties_t>, ObsError>;
+
+    /// Returns the runtime the properties of the object are accessed with. Objects that don't
+    /// provide one can't create a live `ObsPropertiesHandle`.
+    fn get_properties_runtime(&self) -> Option<ObsRuntime> {
+        None
+    }
 }
 
 pub(crate) fn get_properties_inner(
@@ -74,35 +85,46 @@ pub(crate) fn get_properties_inner(
     }
 
     run_with_obs!(runtime, (properties_raw), move || {
-        let mut result = HashMap::new();
-        let mut property = unsafe { libobs::obs_properties_first(properties_raw) };
-        while !property.is_null() {
-            let name = unsafe { libobs::obs_property_name(property) };
-            let name = unsafe { CStr::from_ptr(name as _) };
-            let name = name.to_string_lossy().to_string();
-
-            let p_type = unsafe { libobs::obs_property_get_type(property) };
-            let p_type = ObsPropertyType::from_i32(p_type);
-
-            println!("Property: {:?}", name);
-            match p_type {
-                Some(p_type) => {
-                    result.insert(name, unsafe { p_type.to_property_struct(property) });
-                }
-                None => {
-                    result.insert(name, ObsProperty::Invalid);
-                }
-            }
-
-            // Move to the next property
-            unsafe { libobs::obs_property_next(&mut property) };
-        }
+        let result = unsafe { collect_properties(properties_raw) };
 
         unsafe { libobs::obs_properties_destroy(properties_raw) };
         result
     })
 }
 
+/// Reads all properties of the given `obs_properties_t` without destroying it.
+///
+/// # Safety
+/// `properties_raw` must be a valid pointer and this must be called on the OBS thread.
+pub(crate) unsafe fn collect_properties(
+    properties_raw: *mut obs_properties,
+) -> HashMap<String, ObsProperty> {
+    let mut result = HashMap::new();
+    let mut property = unsafe { libobs::obs_properties_first(properties_raw) };
+    while !property.is_null() {
+        let name = unsafe { libobs::obs_property_name(property) };
+        let name = unsafe { CStr::from_ptr(name as _) };
+        let name = name.to_string_lossy().to_string();
+
+        let p_type = unsafe { libobs::obs_property_get_type(property) };
+        let p_type = ObsPropertyType::from_i32(p_type);
+
+        match p_type {
+            Some(p_type) => {
+                result.insert(name, unsafe { p_type.to_property_struct(property) });
+            }
+            None => {
+                result.insert(name, ObsProperty::Invalid);
+            }
+        }
+
+        // Move to the next property
+        unsafe { libobs::obs_property_next(&mut property) };
+    }
+
+    result
+}
+
 /// This trait is implemented for all obs objects that can have properties
 pub trait ObsPropertyObject: ObsPropertyObjectPrivate {
     /// Returns the properties of the object
@@ -114,4 +136,24 @@ pub trait ObsPropertyObject: ObsPropertyObjectPrivate {
         let properties_raw = Self::get_properties_by_id_raw(id, runtime.clone())?;
         get_properties_inner(properties_raw, runtime.clone())
     }
+
+    /// Returns a live handle to the properties of the object. In contrast to `get_properties`,
+    /// the underlying `obs_properties_t` is kept alive so modified callbacks can be run on it.
+    fn get_properties_handle(&self) -> Result<ObsPropertiesHandle, ObsError> {
+        // Checked first, so the properties are not leaked if there is no runtime to destroy them
+        let runtime = self.get_properties_runtime().ok_or_else(|| {
+            ObsError::InvocationError("The object does not provide a runtime".to_string())
+        })?;
+
+        let properties_raw = self.get_properties_raw()?;
+        ObsPropertiesHandle::new(properties_raw, runtime)
+    }
+
+    fn get_properties_handle_by_id<T: Into<ObsString> + Sync + Send>(
+        id: T,
+        runtime: &ObsRuntime,
+    ) -> Result<ObsPropertiesHandle, ObsError> {
+        let properties_raw = Self::get_properties_by_id_raw(id, runtime.clone())?;
+        ObsPropertiesHandle::new(properties_raw, runtime.clone())
+    }
 }
@@ -42,6 +42,10 @@ impl ObsPropertyObjectPrivate for ObsSourceRef {
             })
             .map_err(|e| ObsError::InvocationError(e.to_string()))
     }
+
+    fn get_properties_runtime(&self) -> Option<ObsRuntime> {
+        Some(self.runtime.clone())
+    }
 }
 
 impl ObsPropertyObject for ObsOutputRef {
@@ -77,4 +81,8 @@ impl ObsPropertyObjectPrivate for ObsOutputRef {
             })
             .map_err(|e| ObsError::InvocationError(e.to_string()))
     }
+
+    fn get_properties_runtime(&self) -> Option<ObsRuntime> {
+        Some(self.runtime.clone())
+    }
 }
@@ -1,14 +1,27 @@
+use std::ffi::c_void;
+
 use getters0::Getters;
+use libobs::obs_properties;
 
-use crate::data::properties::{get_enum, get_opt_str, macros::assert_type, ObsButtonType};
+use crate::{
+    data::properties::{
+        get_enum, get_opt_str, macros::assert_type, ObsButtonType, ObsPropertyObjectPrivate,
+    },
+    run_with_obs,
+    sources::ObsSourceRef,
+    utils::{ObsError, ObsString},
+};
 
 use super::PropertyCreationInfo;
 
-#[derive(Debug, Getters, Clone)]
+#[derive(Debug, Getters, Clone, PartialEq)]
 #[skip_new]
 pub struct ObsButtonProperty {
     name: String,
     description: Option<String>,
+    long_description: Option<String>,
+    visible: bool,
+    enabled: bool,
     button_type: ObsButtonType,
     url: Option<String>,
 }
@@ -18,6 +31,9 @@ impl From<PropertyCreationInfo> for ObsButtonProperty {
         PropertyCreationInfo {
             name,
             description,
+            long_description,
+            visible,
+            enabled,
             pointer,
         }: PropertyCreationInfo,
     ) -> Self {
@@ -29,8 +45,54 @@ impl From<PropertyCreationInfo> for ObsButtonProperty {
         Self {
             name,
             description,
+            long_description,
+            visible,
+            enabled,
             button_type,
             url,
         }
     }
 }
+
+impl ObsButtonProperty {
+    /// Invokes the callback of this button on the given source.
+    ///
+    /// Returns `true` if the callback requested the properties of the source to be refreshed.
+    /// Use `ObsPropertiesHandle::click_button` if the refreshed properties are needed as well.
+    pub fn click(&self, source: &ObsSourceRef) -> Result<bool, ObsError> {
+        let properties_ptr = source.get_properties_raw()?;
+        let source_ptr = source.source.clone();
+        let name = ObsString::new(&self.name);
+        let name_ptr = name.as_ptr();
+
+        run_with_obs!(
+            source.runtime,
+            (properties_ptr, source_ptr, name_ptr),
+            move || unsafe {
+                let result = button_clicked_raw(properties_ptr, name_ptr, source_ptr as _);
+                libobs::obs_properties_destroy(properties_ptr);
+
+                result
+            }
+        )?
+        .ok_or(ObsError::PropertyNotFound)
+    }
+}
+
+/// Runs the callback of the button with the given name. Returns `None` if the property does not
+/// exist.
+///
+/// # Safety
+/// All pointers must be valid and this must be called on the OBS thread.
+pub(crate) unsafe fn button_clicked_raw(
+    properties: *mut obs_properties,
+    name: *const std::os::raw::c_char,
+    object: *mut c_void,
+) -> Option<bool> {
+    let property = libobs::obs_properties_get(properties, name);
+    if property.is_null() {
+        return None;
+    }
+
+    Some(libobs::obs_property_button_clicked(property, object))
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use std::ffi::c_void;
+
+    use super::super::super::{ObsButtonType, ObsProperty, ObsPropertyType};
+    use super::super::button::button_clicked_raw;
+    use crate::utils::ObsString;
+
+    unsafe extern "C" fn on_clicked(
+        _props: *mut libobs::obs_properties_t,
+        _property: *mut libobs::obs_property_t,
+        data: *mut c_void,
+    ) -> bool {
+        let clicks = &mut *(data as *mut u32);
+        *clicks += 1;
+
+        // Requests the properties to be refreshed
+        true
+    }
+
+    #[test]
+    fn test_button_property_conversion() {
+        let name = ObsString::new("open_website");
+        let text = ObsString::new("Open website");
+        let url = ObsString::new("https://obsproject.com");
+
+        unsafe {
+            let props = libobs::obs_properties_create();
+            let property = libobs::obs_properties_add_button2(
+                props,
+                name.as_ptr().0,
+                text.as_ptr().0,
+                None,
+                std::ptr::null_mut(),
+            );
+            libobs::obs_property_button_set_type(property, libobs::obs_button_type_OBS_BUTTON_URL);
+            libobs::obs_property_button_set_url(property, url.as_ptr().0 as *mut _);
+
+            let ObsProperty::Button(button) = ObsPropertyType::Button.to_property_struct(property)
+            else {
+                panic!("Expected a button property");
+            };
+
+            assert_eq!(button.name(), "open_website");
+            assert_eq!(button.description().as_deref(), Some("Open website"));
+            assert_eq!(*button.button_type(), ObsButtonType::Url);
+            assert_eq!(button.url().as_deref(), Some("https://obsproject.com"));
+
+            libobs::obs_properties_destroy(props);
+        }
+    }
+
+    #[test]
+    fn test_button_click() {
+        let name = ObsString::new("refresh");
+        let text = ObsString::new("Refresh");
+        let missing = ObsString::new("missing");
+        let mut clicks = 0u32;
+
+        unsafe {
+            let props = libobs::obs_properties_create();
+            libobs::obs_properties_add_button2(
+                props,
+                name.as_ptr().0,
+                text.as_ptr().0,
+                Some(on_clicked),
+                &mut clicks as *mut u32 as *mut c_void,
+            );
+
+            // The callback gets its private data, so no object is needed
+            let result = button_clicked_raw(props, name.as_ptr().0, std::ptr::null_mut());
+            assert_eq!(result, Some(true));
+
+            let result = button_clicked_raw(props, missing.as_ptr().0, std::ptr::null_mut());
+            assert_eq!(result, None);
+
+            libobs::obs_properties_destroy(props);
+        }
+
+        assert_eq!(clicks, 1);
+    }
+}
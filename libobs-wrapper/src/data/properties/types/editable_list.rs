@@ -4,11 +4,14 @@ use crate::data::properties::{assert_type, get_enum, get_opt_str, ObsEditableLis
 
 use super::PropertyCreationInfo;
 
-#[derive(Debug, Getters, Clone)]
+#[derive(Debug, Getters, Clone, PartialEq)]
 #[skip_new]
 pub struct ObsEditableListProperty {
     name: String,
     description: Option<String>,
+    long_description: Option<String>,
+    visible: bool,
+    enabled: bool,
     list_type: ObsEditableListType,
     filter: String,
     default_path: String,
@@ -19,6 +22,9 @@ impl From<PropertyCreationInfo> for ObsEditableListProperty {
         PropertyCreationInfo {
             name,
             description,
+            long_description,
+            visible,
+            enabled,
             pointer,
         }: PropertyCreationInfo,
     ) -> Self {
@@ -31,6 +37,9 @@ impl From<PropertyCreationInfo> for ObsEditableListProperty {
         Self {
             name,
             description,
+            long_description,
+            visible,
+            enabled,
             list_type,
             filter,
             default_path,
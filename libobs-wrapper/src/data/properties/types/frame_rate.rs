@@ -0,0 +1,116 @@
+use getters0::Getters;
+
+use crate::data::properties::assert_type;
+
+use super::PropertyCreationInfo;
+
+#[derive(Debug, Getters, Clone, PartialEq)]
+#[skip_new]
+pub struct ObsFrameRateProperty {
+    name: String,
+    description: Option<String>,
+    long_description: Option<String>,
+    visible: bool,
+    enabled: bool,
+    /// Named frame rate options (for example "Match output FPS")
+    options: Vec<ObsFrameRateOption>,
+    /// The frame rate ranges that can be selected
+    ranges: Vec<ObsFrameRateRange>,
+}
+
+#[derive(Debug, Getters, Clone, PartialEq)]
+#[skip_new]
+pub struct ObsFrameRateOption {
+    name: String,
+    description: Option<String>,
+}
+
+#[derive(Debug, Getters, Clone, Copy, PartialEq, Eq)]
+#[skip_new]
+pub struct ObsFrameRateRange {
+    min: ObsFramesPerSecond,
+    max: ObsFramesPerSecond,
+}
+
+#[derive(Debug, Getters, Clone, Copy, PartialEq, Eq)]
+#[skip_new]
+pub struct ObsFramesPerSecond {
+    numerator: u32,
+    denominator: u32,
+}
+
+impl ObsFramesPerSecond {
+    /// Returns the frames per second as a floating point number
+    pub fn as_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl From<libobs::media_frames_per_second> for ObsFramesPerSecond {
+    fn from(value: libobs::media_frames_per_second) -> Self {
+        Self {
+            numerator: value.numerator,
+            denominator: value.denominator,
+        }
+    }
+}
+
+impl From<PropertyCreationInfo> for ObsFrameRateProperty {
+    fn from(
+        PropertyCreationInfo {
+            name,
+            description,
+            long_description,
+            visible,
+            enabled,
+            pointer,
+        }: PropertyCreationInfo,
+    ) -> Self {
+        assert_type!(FrameRate, pointer);
+
+        let options_count = unsafe { libobs::obs_property_frame_rate_options_count(pointer) };
+        let mut options = Vec::with_capacity(options_count);
+        for i in 0..options_count {
+            let option_name = unsafe { libobs::obs_property_frame_rate_option_name(pointer, i) };
+            if option_name.is_null() {
+                continue;
+            }
+
+            let option_name = unsafe { std::ffi::CStr::from_ptr(option_name) };
+            let option_description =
+                unsafe { libobs::obs_property_frame_rate_option_description(pointer, i) };
+            let option_description = if option_description.is_null() {
+                None
+            } else {
+                let option_description = unsafe { std::ffi::CStr::from_ptr(option_description) };
+                Some(option_description.to_string_lossy().to_string())
+            };
+
+            options.push(ObsFrameRateOption {
+                name: option_name.to_string_lossy().to_string(),
+                description: option_description,
+            });
+        }
+
+        let ranges_count = unsafe { libobs::obs_property_frame_rate_fps_ranges_count(pointer) };
+        let mut ranges = Vec::with_capacity(ranges_count);
+        for i in 0..ranges_count {
+            let min = unsafe { libobs::obs_property_frame_rate_fps_range_min(pointer, i) };
+            let max = unsafe { libobs::obs_property_frame_rate_fps_range_max(pointer, i) };
+            ranges.push(ObsFrameRateRange {
+                min: min.into(),
+                max: max.into(),
+            });
+        }
+
+        Self {
+            name,
+            description,
+            long_description,
+            visible,
+            enabled,
+            options,
+            ranges,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use super::super::super::{ObsProperty, ObsPropertyType};
+    use crate::utils::ObsString;
+
+    fn fps(numerator: u32, denominator: u32) -> libobs::media_frames_per_second {
+        libobs::media_frames_per_second {
+            numerator,
+            denominator,
+        }
+    }
+
+    #[test]
+    fn test_frame_rate_property_conversion() {
+        let name = ObsString::new("frame_rate");
+        let description = ObsString::new("Frame rate");
+        let option_name = ObsString::new("match");
+        let option_description = ObsString::new("Match output FPS");
+
+        unsafe {
+            let props = libobs::obs_properties_create();
+            let property = libobs::obs_properties_add_frame_rate(
+                props,
+                name.as_ptr().0,
+                description.as_ptr().0,
+            );
+
+            libobs::obs_property_frame_rate_option_add(
+                property,
+                option_name.as_ptr().0,
+                option_description.as_ptr().0,
+            );
+            libobs::obs_property_frame_rate_fps_range_add(property, fps(1, 1), fps(60, 1));
+            libobs::obs_property_frame_rate_fps_range_add(
+                property,
+                fps(30000, 1001),
+                fps(60000, 1001),
+            );
+
+            let ObsProperty::FrameRate(frame_rate) =
+                ObsPropertyType::FrameRate.to_property_struct(property)
+            else {
+                panic!("Expected a frame rate property");
+            };
+
+            assert_eq!(frame_rate.name(), "frame_rate");
+            assert_eq!(frame_rate.options().len(), 1);
+            assert_eq!(frame_rate.options()[0].name(), "match");
+            assert_eq!(
+                frame_rate.options()[0].description().as_deref(),
+                Some("Match output FPS")
+            );
+
+            let ranges = frame_rate.ranges();
+            assert_eq!(ranges.len(), 2);
+            assert_eq!(*ranges[0].min().numerator(), 1);
+            assert_eq!(*ranges[0].max().numerator(), 60);
+            assert_eq!(*ranges[1].min().denominator(), 1001);
+            assert!((ranges[1].max().as_f64() - 59.94).abs() < 0.01);
+
+            libobs::obs_properties_destroy(props);
+        }
+    }
+
+    #[test]
+    fn test_frame_rate_property_without_options() {
+        let name = ObsString::new("frame_rate");
+        let description = ObsString::new("Frame rate");
+
+        unsafe {
+            let props = libobs::obs_properties_create();
+            let property = libobs::obs_properties_add_frame_rate(
+                props,
+                name.as_ptr().0,
+                description.as_ptr().0,
+            );
+
+            let ObsProperty::FrameRate(frame_rate) =
+                ObsPropertyType::FrameRate.to_property_struct(property)
+            else {
+                panic!("Expected a frame rate property");
+            };
+
+            assert!(frame_rate.options().is_empty());
+            assert!(frame_rate.ranges().is_empty());
+
+            libobs::obs_properties_destroy(props);
+        }
+    }
+}
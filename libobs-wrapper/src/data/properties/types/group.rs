@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use getters0::Getters;
+
+use crate::data::properties::{
+    assert_type, collect_properties, get_enum, ObsGroupType, ObsProperty,
+};
+
+use super::PropertyCreationInfo;
+
+#[derive(Debug, Getters, Clone, PartialEq)]
+#[skip_new]
+pub struct ObsGroupProperty {
+    name: String,
+    description: Option<String>,
+    long_description: Option<String>,
+    visible: bool,
+    enabled: bool,
+    group_type: ObsGroupType,
+    /// The properties contained in this group
+    content: HashMap<String, ObsProperty>,
+}
+
+impl From<PropertyCreationInfo> for ObsGroupProperty {
+    fn from(
+        PropertyCreationInfo {
+            name,
+            description,
+            long_description,
+            visible,
+            enabled,
+            pointer,
+        }: PropertyCreationInfo,
+    ) -> Self {
+        assert_type!(Group, pointer);
+
+        let group_type = get_enum!(pointer, group_type, ObsGroupType);
+
+        // The content is owned by the group property, so it must not be destroyed here
+        let content = unsafe { libobs::obs_property_group_content(pointer) };
+        let content = if content.is_null() {
+            HashMap::new()
+        } else {
+            unsafe { collect_properties(content) }
+        };
+
+        Self {
+            name,
+            description,
+            long_description,
+            visible,
+            enabled,
+            group_type,
+            content,
+        }
+    }
+}
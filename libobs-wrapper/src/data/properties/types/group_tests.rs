@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use super::super::super::{ObsGroupType, ObsProperty, ObsPropertyType};
+    use crate::utils::ObsString;
+
+    #[test]
+    fn test_group_property_conversion() {
+        let group_name = ObsString::new("advanced");
+        let group_description = ObsString::new("Advanced");
+        let bool_name = ObsString::new("enabled");
+        let bool_description = ObsString::new("Enabled");
+
+        unsafe {
+            let props = libobs::obs_properties_create();
+
+            // The group takes ownership of its content
+            let content = libobs::obs_properties_create();
+            libobs::obs_properties_add_bool(
+                content,
+                bool_name.as_ptr().0,
+                bool_description.as_ptr().0,
+            );
+
+            let property = libobs::obs_properties_add_group(
+                props,
+                group_name.as_ptr().0,
+                group_description.as_ptr().0,
+                libobs::obs_group_type_OBS_GROUP_CHECKABLE,
+                content,
+            );
+
+            let ObsProperty::Group(group) = ObsPropertyType::Group.to_property_struct(property)
+            else {
+                panic!("Expected a group property");
+            };
+
+            assert_eq!(group.name(), "advanced");
+            assert_eq!(group.description().as_deref(), Some("Advanced"));
+            assert_eq!(*group.group_type(), ObsGroupType::Checkable);
+            assert_eq!(group.content().len(), 1);
+            assert!(matches!(
+                group.content().get("enabled"),
+                Some(ObsProperty::Bool(_))
+            ));
+
+            libobs::obs_properties_destroy(props);
+        }
+    }
+
+    #[test]
+    fn test_empty_group_property_conversion() {
+        let group_name = ObsString::new("empty");
+        let group_description = ObsString::new("Empty");
+
+        unsafe {
+            let props = libobs::obs_properties_create();
+            let content = libobs::obs_properties_create();
+            let property = libobs::obs_properties_add_group(
+                props,
+                group_name.as_ptr().0,
+                group_description.as_ptr().0,
+                libobs::obs_group_type_OBS_GROUP_NORMAL,
+                content,
+            );
+
+            let ObsProperty::Group(group) = ObsPropertyType::Group.to_property_struct(property)
+            else {
+                panic!("Expected a group property");
+            };
+
+            assert_eq!(*group.group_type(), ObsGroupType::Normal);
+            assert!(group.content().is_empty());
+
+            libobs::obs_properties_destroy(props);
+        }
+    }
+}
@@ -3,17 +3,20 @@ use crate::data::properties::{assert_type, get_enum, ObsComboFormat, ObsComboTyp
 use getters0::Getters;
 use std::ffi::CStr;
 
-#[derive(Debug, Getters, Clone)]
+#[derive(Debug, Getters, Clone, PartialEq)]
 #[skip_new]
 pub struct ObsListProperty {
     name: String,
     description: Option<String>,
+    long_description: Option<String>,
+    visible: bool,
+    enabled: bool,
     list_type: ObsComboType,
     format: ObsComboFormat,
     items: Vec<ObsListItem>,
 }
 
-#[derive(Debug, Getters, Clone)]
+#[derive(Debug, Getters, Clone, PartialEq)]
 #[skip_new]
 pub struct ObsListItem {
     name: String,
@@ -21,7 +24,7 @@ pub struct ObsListItem {
     disabled: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ObsListItemValue {
     String(String),
     Int(i64),
@@ -45,6 +48,9 @@ impl From<PropertyCreationInfo> for ObsListProperty {
         PropertyCreationInfo {
             name,
             description,
+            long_description,
+            visible,
+            enabled,
             pointer,
         }: PropertyCreationInfo,
     ) -> Self {
@@ -94,6 +100,9 @@ impl From<PropertyCreationInfo> for ObsListProperty {
         Self {
             name,
             description,
+            long_description,
+            visible,
+            enabled,
             list_type,
             format,
             items,
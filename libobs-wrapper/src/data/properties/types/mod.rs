@@ -1,21 +1,32 @@
 //! # Important Notice
 //! All structs in this module use direct obs calls to get the data from the obs_property_t struct. **ALWAYS MAKE SURE THIS IS RUNNING ON THE OBS THREAD**
 
+impl_general_property!(Bool);
 mod button;
 impl_general_property!(Color);
 mod editable_list;
 impl_general_property!(Font);
-impl_general_property!(FrameRate);
-impl_general_property!(Group);
+mod frame_rate;
+mod group;
 impl_general_property!(ColorAlpha);
 mod list;
 mod number;
 mod path;
 mod text;
 
+#[cfg(test)]
+mod button_tests;
+#[cfg(test)]
+mod frame_rate_tests;
+#[cfg(test)]
+mod group_tests;
+
 pub(crate) struct PropertyCreationInfo {
     pub name: String,
     pub description: Option<String>,
+    pub long_description: Option<String>,
+    pub visible: bool,
+    pub enabled: bool,
     pub pointer: *mut libobs::obs_property,
 }
 
@@ -23,13 +34,18 @@ use std::ffi::CStr;
 
 pub use button::*;
 pub use editable_list::*;
+pub use frame_rate::*;
+pub use group::*;
 use libobs::obs_property;
 pub use list::*;
 pub use number::*;
 pub use path::*;
 pub use text::*;
 
-use super::{macros::impl_general_property, ObsProperty, ObsPropertyType};
+use super::{
+    macros::{get_opt_str, impl_general_property},
+    ObsProperty, ObsPropertyType,
+};
 
 impl ObsPropertyType {
     fn inner_to_property_struct(&self, pointer: *mut obs_property) -> ObsProperty {
@@ -45,15 +61,22 @@ impl ObsPropertyType {
             Some(description.to_string_lossy().to_string())
         };
 
+        let long_description = get_opt_str!(pointer, long_description);
+        let visible = unsafe { libobs::obs_property_visible(pointer) };
+        let enabled = unsafe { libobs::obs_property_enabled(pointer) };
+
         let info = PropertyCreationInfo {
             name,
             description,
+            long_description,
+            visible,
+            enabled,
             pointer,
         };
 
         match self {
             ObsPropertyType::Invalid => ObsProperty::Invalid,
-            ObsPropertyType::Bool => ObsProperty::Bool,
+            ObsPropertyType::Bool => ObsProperty::Bool(ObsBoolProperty::from(info)),
             ObsPropertyType::Int => ObsProperty::Int(ObsNumberProperty::<i32>::from(info)),
             ObsPropertyType::Float => ObsProperty::Float(ObsNumberProperty::<f64>::from(info)),
             ObsPropertyType::Text => ObsProperty::Text(ObsTextProperty::from(info)),
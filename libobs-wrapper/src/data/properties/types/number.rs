@@ -2,7 +2,7 @@ use getters0::Getters;
 
 use crate::data::properties::ObsNumberType;
 
-#[derive(Debug, Getters, Clone)]
+#[derive(Debug, Getters, Clone, PartialEq)]
 #[skip_new]
 pub struct ObsNumberProperty<T>
 where
@@ -10,6 +10,9 @@ where
 {
     name: String,
     description: Option<String>,
+    long_description: Option<String>,
+    visible: bool,
+    enabled: bool,
     min: T,
     max: T,
     step: T,
@@ -25,6 +28,9 @@ macro_rules! impl_from_property {
                     super::PropertyCreationInfo {
                         name,
                         description,
+                        long_description,
+                        visible,
+                        enabled,
                         pointer,
                     }: super::PropertyCreationInfo,
                 ) -> Self {
@@ -53,6 +59,9 @@ macro_rules! impl_from_property {
                     return ObsNumberProperty {
                         name,
                         description,
+                        long_description,
+                        visible,
+                        enabled,
                         min,
                         max,
                         step,
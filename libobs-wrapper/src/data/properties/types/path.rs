@@ -4,11 +4,14 @@ use crate::data::properties::{get_enum, get_opt_str, macros::assert_type, ObsPat
 
 use super::PropertyCreationInfo;
 
-#[derive(Debug, Getters, Clone)]
+#[derive(Debug, Getters, Clone, PartialEq)]
 #[skip_new]
 pub struct ObsPathProperty {
     name: String,
     description: Option<String>,
+    long_description: Option<String>,
+    visible: bool,
+    enabled: bool,
     path_type: ObsPathType,
     filter: String,
     default_path: String,
@@ -19,6 +22,9 @@ impl From<PropertyCreationInfo> for ObsPathProperty {
         PropertyCreationInfo {
             name,
             description,
+            long_description,
+            visible,
+            enabled,
             pointer,
         }: PropertyCreationInfo,
     ) -> Self {
@@ -30,6 +36,9 @@ impl From<PropertyCreationInfo> for ObsPathProperty {
         Self {
             name,
             description,
+            long_description,
+            visible,
+            enabled,
             path_type,
             filter,
             default_path,
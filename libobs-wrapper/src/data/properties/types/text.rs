@@ -4,11 +4,14 @@ use crate::data::properties::{get_enum, macros::assert_type, ObsTextInfoType, Ob
 
 use super::PropertyCreationInfo;
 
-#[derive(Debug, Getters, Clone)]
+#[derive(Debug, Getters, Clone, PartialEq)]
 #[skip_new]
 pub struct ObsTextProperty {
     name: String,
     description: Option<String>,
+    long_description: Option<String>,
+    visible: bool,
+    enabled: bool,
     monospace: bool,
     text_type: ObsTextType,
     info_type: ObsTextInfoType,
@@ -20,6 +23,9 @@ impl From<PropertyCreationInfo> for ObsTextProperty {
         PropertyCreationInfo {
             name,
             description,
+            long_description,
+            visible,
+            enabled,
             pointer,
         }: PropertyCreationInfo,
     ) -> Self {
@@ -34,6 +40,9 @@ impl From<PropertyCreationInfo> for ObsTextProperty {
         ObsTextProperty {
             name,
             description,
+            long_description,
+            visible,
+            enabled,
             monospace,
             text_type,
             info_type,
@@ -0,0 +1,70 @@
+//! Conversion between serde types and `ObsData`.
+//!
+//! The conversion uses the JSON representation of `obs_data`, so structs map to nested
+//! `obs_data` objects and `Vec`s map to `obs_data_array`s. Note that OBS only supports objects
+//! inside of arrays, so a `Vec` of numbers or strings can not be stored and is rejected with
+//! `ObsError::SerializationError`.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{run_with_obs, runtime::ObsRuntime, unsafe_send::Sendable, utils::ObsError};
+
+use super::ObsData;
+
+impl ObsData {
+    /// Creates a new `ObsData` from the given serializable value.
+    /// The value must serialize to a map (for example a struct).
+    ///
+    /// `None` values are skipped, as OBS does not have a null type.
+    pub fn from_serialize<T: Serialize>(value: &T, runtime: ObsRuntime) -> Result<Self, ObsError> {
+        let value =
+            serde_json::to_value(value).map_err(|e| ObsError::SerializationError(e.to_string()))?;
+
+        if !value.is_object() {
+            return Err(ObsError::SerializationError(
+                "Only values that serialize to a map can be converted to ObsData".to_string(),
+            ));
+        }
+
+        check_arrays(&value, "")?;
+        Self::from_json(&value.to_string(), runtime)
+    }
+
+    /// Deserializes the content of this `ObsData` (including default values) into the given type.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, ObsError> {
+        let data_ptr = self.as_ptr();
+        let json = run_with_obs!(self.runtime, (data_ptr), move || unsafe {
+            Sendable(libobs::obs_data_get_json_with_defaults(data_ptr))
+        })?;
+
+        if json.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let json = unsafe { std::ffi::CStr::from_ptr(json.0) };
+        let json = json.to_str().map_err(|_| ObsError::StringConversionError)?;
+
+        serde_json::from_str(json).map_err(|e| ObsError::SerializationError(e.to_string()))
+    }
+}
+
+/// OBS silently drops array items that are not objects, so these are rejected before converting.
+pub(crate) fn check_arrays(value: &serde_json::Value, path: &str) -> Result<(), ObsError> {
+    match value {
+        serde_json::Value::Object(map) => map
+            .iter()
+            .try_for_each(|(key, value)| check_arrays(value, &format!("{path}.{key}"))),
+        serde_json::Value::Array(items) => items.iter().enumerate().try_for_each(|(i, item)| {
+            if !item.is_object() {
+                return Err(ObsError::SerializationError(format!(
+                    "Array item {}[{}] is not a map, OBS only supports maps inside of arrays",
+                    path.trim_start_matches('.'),
+                    i
+                )));
+            }
+
+            check_arrays(item, &format!("{path}[{i}]"))
+        }),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::super::serde_bridge::check_arrays;
+    use crate::utils::ObsError;
+
+    #[test]
+    fn test_check_arrays() {
+        let valid = json!({"a": 1, "items": [{"b": "c", "nested": [{"d": true}]}]});
+        assert_eq!(check_arrays(&valid, ""), Ok(()));
+
+        let numbers = json!({"a": 1, "values": [1, 2, 3]});
+        assert!(matches!(
+            check_arrays(&numbers, ""),
+            Err(ObsError::SerializationError(msg)) if msg.contains("values[0]")
+        ));
+
+        let nested = json!({"items": [{"names": ["x"]}]});
+        assert!(matches!(
+            check_arrays(&nested, ""),
+            Err(ObsError::SerializationError(msg)) if msg.contains("items[0].names[0]")
+        ));
+    }
+}
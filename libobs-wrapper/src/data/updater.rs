@@ -8,7 +8,7 @@ use crate::{
     utils::{ObsError, ObsString},
 };
 
-use super::_ObsDataDropGuard;
+use super::{ObsData, ObsDataArray, _ObsDataDropGuard};
 
 #[derive(Debug)]
 pub enum ObsDataChange {
@@ -16,6 +16,10 @@ pub enum ObsDataChange {
     Int(ObsString, i64),
     Bool(ObsString, bool),
     Double(ObsString, f64),
+    Object(ObsString, ObsData),
+    Array(ObsString, ObsDataArray),
+    /// Removes the user value of the key
+    Erase(ObsString),
 }
 
 #[derive(Debug)]
@@ -62,6 +66,46 @@ impl ObsDataUpdater {
         self
     }
 
+    pub fn set_double_ref(&mut self, key: impl Into<ObsString>, value: f64) {
+        let key = key.into();
+        self.changes.push(ObsDataChange::Double(key, value));
+    }
+
+    pub fn set_double(mut self, key: impl Into<ObsString>, value: f64) -> Self {
+        self.set_double_ref(key, value);
+        self
+    }
+
+    pub fn set_obj_ref(&mut self, key: impl Into<ObsString>, value: ObsData) {
+        let key = key.into();
+        self.changes.push(ObsDataChange::Object(key, value));
+    }
+
+    pub fn set_obj(mut self, key: impl Into<ObsString>, value: ObsData) -> Self {
+        self.set_obj_ref(key, value);
+        self
+    }
+
+    pub fn set_array_ref(&mut self, key: impl Into<ObsString>, value: ObsDataArray) {
+        let key = key.into();
+        self.changes.push(ObsDataChange::Array(key, value));
+    }
+
+    pub fn set_array(mut self, key: impl Into<ObsString>, value: ObsDataArray) -> Self {
+        self.set_array_ref(key, value);
+        self
+    }
+
+    pub fn erase_ref(&mut self, key: impl Into<ObsString>) {
+        let key = key.into();
+        self.changes.push(ObsDataChange::Erase(key));
+    }
+
+    pub fn erase(mut self, key: impl Into<ObsString>) -> Self {
+        self.erase_ref(key);
+        self
+    }
+
     pub fn update(self) -> Result<(), ObsError> {
         let ObsDataUpdater {
             changes,
@@ -70,23 +114,59 @@ impl ObsDataUpdater {
         } = self;
 
         let obs_data = obs_data.clone();
-        run_with_obs!(_drop_guard.runtime, (obs_data), move || unsafe {
-            for change in changes {
-                match change {
-                    ObsDataChange::String(key, value) => {
-                        libobs::obs_data_set_string(obs_data, key.as_ptr().0, value.as_ptr().0)
-                    }
-                    ObsDataChange::Int(key, value) => {
-                        libobs::obs_data_set_int(obs_data, key.as_ptr().0, value)
-                    }
-                    ObsDataChange::Bool(key, value) => {
-                        libobs::obs_data_set_bool(obs_data, key.as_ptr().0, value)
-                    }
-                    ObsDataChange::Double(key, value) => {
-                        libobs::obs_data_set_double(obs_data, key.as_ptr().0, value)
-                    }
-                };
+        let changes = run_with_obs!(_drop_guard.runtime, (obs_data), move || unsafe {
+            for change in changes.iter() {
+                apply_change_raw(obs_data, change);
             }
-        })
+
+            // Objects and arrays must not be dropped on the OBS thread
+            changes
+        })?;
+
+        drop(changes);
+        Ok(())
     }
 }
+
+impl ObsDataChange {
+    /// Returns the key this change is applied to.
+    pub fn key(&self) -> &ObsString {
+        match self {
+            ObsDataChange::String(key, _)
+            | ObsDataChange::Int(key, _)
+            | ObsDataChange::Bool(key, _)
+            | ObsDataChange::Double(key, _)
+            | ObsDataChange::Object(key, _)
+            | ObsDataChange::Array(key, _)
+            | ObsDataChange::Erase(key) => key,
+        }
+    }
+}
+
+/// Writes a single change to the given `obs_data`.
+///
+/// # Safety
+/// `obs_data` must be a valid pointer and this must be called on the OBS thread.
+pub(crate) unsafe fn apply_change_raw(obs_data: *mut obs_data, change: &ObsDataChange) {
+    match change {
+        ObsDataChange::String(key, value) => {
+            libobs::obs_data_set_string(obs_data, key.as_ptr().0, value.as_ptr().0)
+        }
+        ObsDataChange::Int(key, value) => {
+            libobs::obs_data_set_int(obs_data, key.as_ptr().0, *value)
+        }
+        ObsDataChange::Bool(key, value) => {
+            libobs::obs_data_set_bool(obs_data, key.as_ptr().0, *value)
+        }
+        ObsDataChange::Double(key, value) => {
+            libobs::obs_data_set_double(obs_data, key.as_ptr().0, *value)
+        }
+        ObsDataChange::Object(key, value) => {
+            libobs::obs_data_set_obj(obs_data, key.as_ptr().0, value.as_ptr().0)
+        }
+        ObsDataChange::Array(key, value) => {
+            libobs::obs_data_set_array(obs_data, key.as_ptr().0, value.as_ptr().0)
+        }
+        ObsDataChange::Erase(key) => libobs::obs_data_unset_user_value(obs_data, key.as_ptr().0),
+    };
+}
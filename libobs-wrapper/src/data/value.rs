@@ -0,0 +1,254 @@
+//! A pure Rust representation of the content of `obs_data`.
+//!
+//! `ObsValue` trees can be compared without calling into OBS, which makes it possible to compute
+//! the difference between two settings snapshots (for example for logging or undo) and to replay
+//! that difference through an `ObsDataUpdater` with `apply_patch`.
+
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Number, Value};
+
+use crate::{run_with_obs, unsafe_send::Sendable, utils::ObsError};
+
+use super::{immutable::ImmutableObsData, ObsData, ObsDataChange, ObsDataUpdater};
+
+/// A single value stored in `obs_data`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObsValue {
+    Null,
+    String(String),
+    Int(i64),
+    Double(f64),
+    Bool(bool),
+    Object(BTreeMap<String, ObsValue>),
+    /// OBS only supports objects as array items
+    Array(Vec<ObsValue>),
+}
+
+impl ObsValue {
+    /// Parses the JSON representation of `obs_data` into an `ObsValue`.
+    pub fn from_json(json: &str) -> Result<Self, ObsError> {
+        let value: Value = serde_json::from_str(json).map_err(|_| ObsError::JsonParseError)?;
+        Ok(value.into())
+    }
+
+    /// Returns the JSON representation of this value.
+    pub fn to_json(&self) -> String {
+        Value::from(self).to_string()
+    }
+
+    /// Returns the entries of this value if it is an object.
+    pub fn as_object(&self) -> Option<&BTreeMap<String, ObsValue>> {
+        match self {
+            ObsValue::Object(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Computes the changes needed to turn the object `old` into the object `new`.
+    ///
+    /// The changes operate on the top level keys, nested objects and arrays are replaced as a
+    /// whole. Values that are not objects are treated as empty objects. The changes are sorted
+    /// by key.
+    pub fn diff(old: &ObsValue, new: &ObsValue) -> Vec<ObsValueChange> {
+        let empty = BTreeMap::new();
+        let old = old.as_object().unwrap_or(&empty);
+        let new = new.as_object().unwrap_or(&empty);
+
+        let mut changes = Vec::new();
+        for (key, new_value) in new {
+            let old_value = old.get(key);
+            if old_value == Some(new_value) {
+                continue;
+            }
+
+            changes.push(ObsValueChange::Set {
+                key: key.clone(),
+                old: old_value.cloned(),
+                new: new_value.clone(),
+            });
+        }
+
+        for (key, old_value) in old {
+            if !new.contains_key(key) {
+                changes.push(ObsValueChange::Erase {
+                    key: key.clone(),
+                    old: old_value.clone(),
+                });
+            }
+        }
+
+        changes.sort_by(|a, b| a.key().cmp(b.key()));
+        changes
+    }
+}
+
+impl From<Value> for ObsValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => ObsValue::Null,
+            Value::Bool(b) => ObsValue::Bool(b),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => ObsValue::Int(i),
+                None => ObsValue::Double(n.as_f64().unwrap_or_default()),
+            },
+            Value::String(s) => ObsValue::String(s),
+            Value::Array(items) => ObsValue::Array(items.into_iter().map(Into::into).collect()),
+            Value::Object(map) => {
+                ObsValue::Object(map.into_iter().map(|(k, v)| (k, v.into())).collect())
+            }
+        }
+    }
+}
+
+impl From<&ObsValue> for Value {
+    fn from(value: &ObsValue) -> Self {
+        match value {
+            ObsValue::Null => Value::Null,
+            ObsValue::String(s) => Value::String(s.clone()),
+            ObsValue::Int(i) => Value::Number((*i).into()),
+            ObsValue::Double(d) => Number::from_f64(*d).map_or(Value::Null, Value::Number),
+            ObsValue::Bool(b) => Value::Bool(*b),
+            ObsValue::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(k, v)| (k.clone(), v.into()))
+                    .collect::<Map<_, _>>(),
+            ),
+            ObsValue::Array(items) => Value::Array(items.iter().map(Into::into).collect()),
+        }
+    }
+}
+
+/// A single operation of a settings diff, see `ObsValue::diff`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObsValueChange {
+    /// The key was added or its value changed
+    Set {
+        key: String,
+        old: Option<ObsValue>,
+        new: ObsValue,
+    },
+    /// The user value of the key was removed
+    Erase { key: String, old: ObsValue },
+}
+
+impl ObsValueChange {
+    pub fn key(&self) -> &str {
+        match self {
+            ObsValueChange::Set { key, .. } | ObsValueChange::Erase { key, .. } => key,
+        }
+    }
+
+    /// Returns the change that reverts this change.
+    pub fn inverted(&self) -> Self {
+        match self {
+            ObsValueChange::Set {
+                key,
+                old: Some(old),
+                new,
+            } => ObsValueChange::Set {
+                key: key.clone(),
+                old: Some(new.clone()),
+                new: old.clone(),
+            },
+            ObsValueChange::Set {
+                key,
+                old: None,
+                new,
+            } => ObsValueChange::Erase {
+                key: key.clone(),
+                old: new.clone(),
+            },
+            ObsValueChange::Erase { key, old } => ObsValueChange::Set {
+                key: key.clone(),
+                old: None,
+                new: old.clone(),
+            },
+        }
+    }
+}
+
+/// Returns the changes that revert the given patch, in the order they must be applied.
+pub fn invert_patch(patch: &[ObsValueChange]) -> Vec<ObsValueChange> {
+    patch.iter().rev().map(ObsValueChange::inverted).collect()
+}
+
+impl ImmutableObsData {
+    /// Reads the user values of this data into an `ObsValue` tree. Default values are not
+    /// included.
+    pub fn to_value(&self) -> Result<ObsValue, ObsError> {
+        let ptr = self.as_ptr();
+        let json = run_with_obs!(self.runtime, (ptr), move || unsafe {
+            Sendable(libobs::obs_data_get_json(ptr))
+        })?;
+
+        if json.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let json = unsafe { std::ffi::CStr::from_ptr(json.0) }
+            .to_str()
+            .map_err(|_| ObsError::JsonParseError)?;
+
+        ObsValue::from_json(json)
+    }
+
+    /// Computes the changes needed to turn this data into `other`.
+    pub fn diff(&self, other: &ImmutableObsData) -> Result<Vec<ObsValueChange>, ObsError> {
+        Ok(ObsValue::diff(&self.to_value()?, &other.to_value()?))
+    }
+}
+
+impl ObsDataUpdater {
+    /// Queues the given changes (see `ObsValue::diff`) on this updater.
+    pub fn apply_patch(mut self, patch: &[ObsValueChange]) -> Result<Self, ObsError> {
+        for change in patch {
+            let change = match change {
+                ObsValueChange::Erase { key, .. }
+                | ObsValueChange::Set {
+                    key,
+                    new: ObsValue::Null,
+                    ..
+                } => ObsDataChange::Erase(key.as_str().into()),
+                ObsValueChange::Set { key, new, .. } => self.value_to_change(key, new)?,
+            };
+
+            self.changes.push(change);
+        }
+
+        Ok(self)
+    }
+
+    fn value_to_change(&self, key: &str, value: &ObsValue) -> Result<ObsDataChange, ObsError> {
+        let key = key.into();
+        let change = match value {
+            ObsValue::String(s) => ObsDataChange::String(key, s.as_str().into()),
+            ObsValue::Int(i) => ObsDataChange::Int(key, *i),
+            ObsValue::Double(d) => ObsDataChange::Double(key, *d),
+            ObsValue::Bool(b) => ObsDataChange::Bool(key, *b),
+            ObsValue::Null => ObsDataChange::Erase(key),
+            ObsValue::Object(_) => {
+                let data = ObsData::from_json(&value.to_json(), self._drop_guard.runtime.clone())?;
+                ObsDataChange::Object(key, data)
+            }
+            ObsValue::Array(_) => {
+                // Arrays can only be created from JSON as part of an object
+                let mut wrapper = BTreeMap::new();
+                wrapper.insert("array".to_string(), value.clone());
+
+                let wrapper = ObsData::from_json(
+                    &ObsValue::Object(wrapper).to_json(),
+                    self._drop_guard.runtime.clone(),
+                )?;
+
+                let array = wrapper
+                    .get_array("array")?
+                    .ok_or(ObsError::JsonParseError)?;
+
+                ObsDataChange::Array(key, array)
+            }
+        };
+
+        Ok(change)
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{invert_patch, ObsValue, ObsValueChange};
+
+    #[test]
+    fn test_value_from_json() {
+        let value = ObsValue::from_json(
+            r#"{"name": "test", "count": 5, "ratio": 1.5, "enabled": true, "nested": {"a": 1}, "items": [{"b": "c"}]}"#,
+        )
+        .unwrap();
+
+        let map = value.as_object().unwrap();
+        assert_eq!(map["name"], ObsValue::String("test".to_string()));
+        assert_eq!(map["count"], ObsValue::Int(5));
+        assert_eq!(map["ratio"], ObsValue::Double(1.5));
+        assert_eq!(map["enabled"], ObsValue::Bool(true));
+        assert_eq!(map["nested"].as_object().unwrap()["a"], ObsValue::Int(1));
+        assert!(matches!(&map["items"], ObsValue::Array(items) if items.len() == 1));
+    }
+
+    #[test]
+    fn test_value_json_roundtrip() {
+        let value = ObsValue::from_json(r#"{"a": 1, "b": 2.5, "c": [{"d": false}]}"#).unwrap();
+        let roundtrip = ObsValue::from_json(&value.to_json()).unwrap();
+        assert_eq!(value, roundtrip);
+    }
+
+    #[test]
+    fn test_value_from_invalid_json() {
+        assert!(ObsValue::from_json("{invalid").is_err());
+    }
+
+    #[test]
+    fn test_diff_equal() {
+        let value = ObsValue::from_json(r#"{"a": 1, "nested": {"b": 2}}"#).unwrap();
+        assert!(ObsValue::diff(&value, &value.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_set_and_erase() {
+        let old = ObsValue::from_json(r#"{"a": 1, "b": "x", "nested": {"c": 2}}"#).unwrap();
+        let new = ObsValue::from_json(r#"{"a": 2, "nested": {"c": 3}, "d": true}"#).unwrap();
+
+        let diff = ObsValue::diff(&old, &new);
+        assert_eq!(
+            diff,
+            vec![
+                ObsValueChange::Set {
+                    key: "a".to_string(),
+                    old: Some(ObsValue::Int(1)),
+                    new: ObsValue::Int(2),
+                },
+                ObsValueChange::Erase {
+                    key: "b".to_string(),
+                    old: ObsValue::String("x".to_string()),
+                },
+                ObsValueChange::Set {
+                    key: "d".to_string(),
+                    old: None,
+                    new: ObsValue::Bool(true),
+                },
+                ObsValueChange::Set {
+                    key: "nested".to_string(),
+                    old: Some(ObsValue::from_json(r#"{"c": 2}"#).unwrap()),
+                    new: ObsValue::from_json(r#"{"c": 3}"#).unwrap(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invert_patch() {
+        let old = ObsValue::from_json(r#"{"a": 1, "b": "x"}"#).unwrap();
+        let new = ObsValue::from_json(r#"{"a": 2, "c": true}"#).unwrap();
+
+        let diff = ObsValue::diff(&old, &new);
+        let inverted = invert_patch(&diff);
+
+        // Reverting the changes must produce the diff from new to old
+        let mut expected = ObsValue::diff(&new, &old);
+        let mut inverted_sorted = inverted.clone();
+        expected.sort_by(|a, b| a.key().cmp(b.key()));
+        inverted_sorted.sort_by(|a, b| a.key().cmp(b.key()));
+        assert_eq!(inverted_sorted, expected);
+    }
+}
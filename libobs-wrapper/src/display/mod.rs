@@ -3,15 +3,21 @@
 
 mod creation_data;
 mod enums;
+mod offscreen;
+mod overlay;
+mod render_target;
 mod window_manager;
 
+#[cfg(test)]
+mod render_target_tests;
+
 pub use creation_data::*;
 pub use enums::*;
-use libobs::obs_video_info;
+pub use offscreen::*;
+pub use overlay::*;
+pub use render_target::*;
 pub use window_manager::*;
 
-use libobs::obs_render_main_texture_src_color_only;
-use std::mem::MaybeUninit;
 use std::{
     ffi::c_void,
     marker::PhantomPinned,
@@ -19,7 +25,7 @@ use std::{
 };
 
 use crate::{
-    impl_obs_drop, run_with_obs, runtime::ObsRuntime, unsafe_send::Sendable, utils::ObsError,
+    impl_obs_drop, run_with_obs, runtime::ObsRuntime, unsafe_send::Sendable, utils::ObsError, Vec2,
 };
 
 static ID_COUNTER: AtomicUsize = AtomicUsize::new(1);
@@ -38,6 +44,8 @@ pub struct ObsDisplayRef {
 
     // Keep for window, manager is accessed by render thread as well so Arc and RwLock
     manager: Arc<RwLock<DisplayWindowManager>>,
+    /// Read by the draw callback, which owns another reference that is released by the drop guard
+    render_state: Arc<DisplayRenderState>,
     /// This must not be moved in memory as the draw callback is a raw pointer to this struct
     _fixed_in_heap: PhantomPinned,
 
@@ -45,30 +53,22 @@ pub struct ObsDisplayRef {
     pub(crate) runtime: ObsRuntime,
 }
 
-unsafe extern "C" fn render_display(_data: *mut c_void, width: u32, height: u32) {
-    let mut ovi = MaybeUninit::<obs_video_info>::uninit();
-    libobs::obs_get_video_info(ovi.as_mut_ptr());
-
-    let ovi = unsafe { ovi.assume_init() };
+/// State of a display that is shared with the draw callback
+#[derive(Debug, Default)]
+struct DisplayRenderState {
+    target: RwLock<OwnedRenderTarget>,
+    overlay: RwLock<Option<ObsSelectionOverlay>>,
+}
 
-    libobs::gs_viewport_push();
-    libobs::gs_projection_push();
+unsafe extern "C" fn render_display(data: *mut c_void, width: u32, height: u32) {
+    let state = &*(data as *const DisplayRenderState);
+    let Ok(target) = state.target.read() else {
+        return;
+    };
+    let overlay = state.overlay.read().ok().and_then(|overlay| *overlay);
 
-    libobs::gs_ortho(
-        0.0f32,
-        ovi.base_width as f32,
-        0.0f32,
-        ovi.base_height as f32,
-        -100.0f32,
-        100.0f32,
-    );
-    libobs::gs_set_viewport(0, 0, width as i32, height as i32);
     //draw_backdrop(&s.buffers, ovi.base_width as f32, ovi.base_height as f32);
-
-    obs_render_main_texture_src_color_only();
-
-    libobs::gs_projection_pop();
-    libobs::gs_viewport_pop();
+    target.render_letterboxed(width, height, overlay.as_ref());
 }
 
 impl ObsDisplayRef {
@@ -120,13 +120,18 @@ impl ObsDisplayRef {
         }
 
         manager.obs_display = Some(display.clone());
+
+        let render_state = Arc::new(DisplayRenderState::default());
+        let render_param = Sendable(Arc::into_raw(render_state.clone()) as *mut c_void);
         let instance = Box::pin(Self {
             display: display.clone(),
             _guard: Arc::new(_ObsDisplayDropGuard {
                 display,
+                render_param: render_param.clone(),
                 runtime: runtime.clone(),
             }),
             manager: Arc::new(RwLock::new(manager)),
+            render_state,
             id: ID_COUNTER.fetch_add(1, Ordering::Relaxed),
             _fixed_in_heap: PhantomPinned,
             runtime: runtime.clone(),
@@ -140,12 +145,8 @@ impl ObsDisplayRef {
         );
 
         let display_ptr = instance.display.clone();
-        run_with_obs!(runtime, (display_ptr), move || unsafe {
-            libobs::obs_display_add_draw_callback(
-                display_ptr,
-                Some(render_display),
-                std::ptr::null_mut(),
-            );
+        run_with_obs!(runtime, (display_ptr, render_param), move || unsafe {
+            libobs::obs_display_add_draw_callback(display_ptr, Some(render_display), render_param);
         })?;
 
         // Set the display pointer in the window's user data for message handling
@@ -164,17 +165,127 @@ impl ObsDisplayRef {
     pub fn id(&self) -> usize {
         self.id
     }
+
+    /// Sets what this display renders. The target is scaled to fit the display while keeping
+    /// its aspect ratio, the remaining area is filled with the background color.
+    ///
+    /// The display keeps the scene or source alive until another target is set.
+    pub fn set_render_target(&self, target: RenderTarget) -> Result<(), ObsError> {
+        let target = OwnedRenderTarget::from(target);
+
+        // Sources only update their content while they are shown
+        let new_source = Sendable(unsafe { target.source_ptr() });
+        run_with_obs!(self.runtime, (new_source), move || unsafe {
+            if !new_source.is_null() {
+                libobs::obs_source_inc_showing(new_source);
+            }
+        })?;
+
+        // The lock must not be held while calling into OBS, the render thread waits for it
+        let old_target = {
+            let mut current = self
+                .render_state
+                .target
+                .write()
+                .map_err(|e| ObsError::LockError(format!("{:?}", e)))?;
+
+            std::mem::replace(&mut *current, target)
+        };
+
+        let old_source = Sendable(unsafe { old_target.source_ptr() });
+        run_with_obs!(self.runtime, (old_source), move || unsafe {
+            if !old_source.is_null() {
+                libobs::obs_source_dec_showing(old_source);
+            }
+        })
+    }
+
+    /// Returns the size of the current render target in canvas pixels.
+    /// For the main view, this is the base resolution.
+    pub fn get_render_target_size(&self) -> Result<(u32, u32), ObsError> {
+        let source = {
+            let target = self
+                .render_state
+                .target
+                .read()
+                .map_err(|e| ObsError::LockError(format!("{:?}", e)))?;
+
+            Sendable(unsafe { target.source_ptr() })
+        };
+
+        run_with_obs!(self.runtime, (source), move || unsafe {
+            get_base_size(source)
+        })
+    }
+
+    /// Draws outlines and resize handles around the selected items of the shown scene,
+    /// or disables the overlay with `None`. Displays showing a single source draw no overlay.
+    pub fn set_selection_overlay(
+        &self,
+        overlay: Option<ObsSelectionOverlay>,
+    ) -> Result<(), ObsError> {
+        let mut current = self
+            .render_state
+            .overlay
+            .write()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))?;
+
+        *current = overlay;
+        Ok(())
+    }
+
+    fn get_geometry(&self) -> Result<DisplayGeometry, ObsError> {
+        Ok(DisplayGeometry {
+            pos: self.get_pos()?,
+            size: self.get_size()?,
+            scale: self.get_scale()?,
+            base_size: self.get_render_target_size()?,
+        })
+    }
+
+    /// Maps a point of the window the display is in to canvas coordinates of the render target,
+    /// e.g. to find the scene item under the cursor with `ObsSceneRef::item_at`.
+    ///
+    /// The point is multiplied by the scale of the display (see `set_scale`) and the position of
+    /// the display and the letterboxing are taken into account. Points outside of the rendered
+    /// area map to coordinates outside of the canvas.
+    pub fn preview_to_canvas(&self, point: Vec2) -> Result<Vec2, ObsError> {
+        Ok(self.get_geometry()?.preview_to_canvas(point))
+    }
+
+    /// Maps a point in canvas coordinates to the window the display is in,
+    /// the inverse of `preview_to_canvas`.
+    pub fn canvas_to_preview(&self, point: Vec2) -> Result<Vec2, ObsError> {
+        Ok(self.get_geometry()?.canvas_to_preview(point))
+    }
 }
 
 #[derive(Debug)]
 struct _ObsDisplayDropGuard {
     display: Sendable<*mut libobs::obs_display_t>,
+    render_param: Sendable<*mut c_void>,
     pub(crate) runtime: ObsRuntime,
 }
 
-impl_obs_drop!(_ObsDisplayDropGuard, (display), move || unsafe {
-    log::trace!("Removing callback of display {:?}...", display);
-    libobs::obs_display_remove_draw_callback(display, Some(render_display), std::ptr::null_mut());
+impl_obs_drop!(
+    _ObsDisplayDropGuard,
+    (display, render_param),
+    move || unsafe {
+        log::trace!("Removing callback of display {:?}...", display);
+        libobs::obs_display_remove_draw_callback(display, Some(render_display), render_param);
 
-    libobs::obs_display_destroy(display);
-});
+        libobs::obs_display_destroy(display);
+
+        let render_state = Arc::from_raw(render_param as *const DisplayRenderState);
+        if let Ok(target) = render_state.target.read() {
+            let source = target.source_ptr();
+            if !source.is_null() {
+                libobs::obs_source_dec_showing(source);
+            }
+        }
+
+        // Dropping the target may release the last reference to a source, which must not happen
+        // on the OBS thread
+        Sendable(render_state)
+    }
+);
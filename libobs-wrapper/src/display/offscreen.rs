@@ -0,0 +1,392 @@
+//! Renders the main view, a scene or a single source into a texture without creating a window.
+//!
+//! The rendered texture is copied back to the CPU after every rendered frame of OBS (limited by
+//! the maximum frame rate of the preview) and sent as tightly packed RGBA pixels to all
+//! subscribers. This is useful for previews in web-based UIs or for comparing frames in tests.
+
+use std::{
+    ffi::c_void,
+    mem::MaybeUninit,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use getters0::Getters;
+use tokio::sync::broadcast;
+
+use crate::{
+    impl_obs_drop, run_with_obs, runtime::ObsRuntime, unsafe_send::Sendable, utils::ObsError,
+};
+
+use super::{OwnedRenderTarget, RenderTarget};
+
+/// A frame rendered by an `ObsOffscreenPreview`.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct ObsPreviewFrame {
+    width: u32,
+    height: u32,
+    /// The pixels of the frame in RGBA format, 4 bytes per pixel without padding between rows
+    data: Vec<u8>,
+}
+
+impl ObsPreviewFrame {
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ObsOffscreenPreviewCreationData {
+    pub(super) width: u32,
+    pub(super) height: u32,
+    pub(super) max_fps: Option<f64>,
+    pub(super) target: OwnedRenderTarget,
+}
+
+impl ObsOffscreenPreviewCreationData {
+    /// Renders the main view into frames of the given size. If the aspect ratio differs from
+    /// the base resolution, the view is letterboxed with black bars.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            max_fps: None,
+            target: OwnedRenderTarget::Main,
+        }
+    }
+
+    /// Limits how often frames are rendered. Without a limit, a frame is rendered for every
+    /// frame of the OBS video pipeline.
+    pub fn set_max_fps(mut self, max_fps: f64) -> Self {
+        self.max_fps = Some(max_fps);
+        self
+    }
+
+    /// Renders the given scene or source instead of the main view.
+    pub fn set_render_target(mut self, target: RenderTarget) -> Self {
+        self.target = target.into();
+        self
+    }
+}
+
+/// Graphics objects of the preview, these must only be used inside the graphics context.
+#[derive(Debug)]
+struct PreviewGraphics {
+    texrender: *mut libobs::gs_texrender_t,
+    stagesurface: *mut libobs::gs_stagesurf_t,
+    width: u32,
+    height: u32,
+    /// Whether the stage surface contains a frame that has not been read yet
+    staged: bool,
+}
+
+// The graphics objects are only accessed from the graphics thread and while dropping the preview
+unsafe impl Send for PreviewGraphics {}
+
+impl PreviewGraphics {
+    unsafe fn new(width: u32, height: u32) -> Option<Self> {
+        let texrender = libobs::gs_texrender_create(
+            libobs::gs_color_format_GS_RGBA,
+            libobs::gs_zstencil_format_GS_ZS_NONE,
+        );
+        let stagesurface =
+            libobs::gs_stagesurface_create(width, height, libobs::gs_color_format_GS_RGBA);
+
+        let graphics = Self {
+            texrender,
+            stagesurface,
+            width,
+            height,
+            staged: false,
+        };
+
+        if texrender.is_null() || stagesurface.is_null() {
+            graphics.destroy();
+            return None;
+        }
+
+        Some(graphics)
+    }
+
+    unsafe fn destroy(self) {
+        if !self.stagesurface.is_null() {
+            libobs::gs_stagesurface_destroy(self.stagesurface);
+        }
+
+        if !self.texrender.is_null() {
+            libobs::gs_texrender_destroy(self.texrender);
+        }
+    }
+
+    /// Copies the staged frame to the CPU. Mapping is done one OBS frame after staging,
+    /// so the GPU has time to finish the copy and the render thread is not stalled.
+    unsafe fn read_staged(&mut self) -> Option<ObsPreviewFrame> {
+        if !self.staged {
+            return None;
+        }
+        self.staged = false;
+
+        let mut data = std::ptr::null_mut();
+        let mut linesize = 0;
+        if !libobs::gs_stagesurface_map(self.stagesurface, &mut data, &mut linesize) {
+            return None;
+        }
+
+        let row_len = self.width as usize * 4;
+        let mut pixels = Vec::with_capacity(row_len * self.height as usize);
+        for row in 0..self.height as usize {
+            let row_ptr = data.add(row * linesize as usize);
+            pixels.extend_from_slice(std::slice::from_raw_parts(row_ptr, row_len));
+        }
+
+        libobs::gs_stagesurface_unmap(self.stagesurface);
+
+        Some(ObsPreviewFrame {
+            width: self.width,
+            height: self.height,
+            data: pixels,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct PreviewSettings {
+    width: u32,
+    height: u32,
+    min_interval: Option<Duration>,
+    last_render: Option<Instant>,
+}
+
+/// State shared between the preview and the render callback
+#[derive(Debug)]
+struct PreviewState {
+    target: OwnedRenderTarget,
+    settings: Mutex<PreviewSettings>,
+    graphics: Mutex<Option<PreviewGraphics>>,
+    sender: broadcast::Sender<Arc<ObsPreviewFrame>>,
+}
+
+fn min_interval(max_fps: Option<f64>) -> Option<Duration> {
+    max_fps
+        .filter(|fps| *fps > 0.0)
+        .map(|fps| Duration::from_secs_f64(1.0 / fps))
+}
+
+unsafe extern "C" fn render_offscreen_preview(param: *mut c_void) {
+    let state = &*(param as *const PreviewState);
+
+    let Ok(mut graphics) = state.graphics.lock() else {
+        return;
+    };
+
+    // Read the frame of the last render first, so the stage surface can be reused
+    if let Some(frame) = graphics.as_mut().and_then(|g| g.read_staged()) {
+        let _ = state.sender.send(Arc::new(frame));
+    }
+
+    // Nobody is listening, so there is no need to render
+    if state.sender.receiver_count() == 0 {
+        return;
+    }
+
+    let (width, height) = {
+        let Ok(mut settings) = state.settings.lock() else {
+            return;
+        };
+
+        let now = Instant::now();
+        let is_due = match (settings.last_render, settings.min_interval) {
+            (Some(last), Some(interval)) => now.duration_since(last) >= interval,
+            _ => true,
+        };
+
+        if !is_due || settings.width == 0 || settings.height == 0 {
+            return;
+        }
+
+        settings.last_render = Some(now);
+        (settings.width, settings.height)
+    };
+
+    let size_changed = graphics
+        .as_ref()
+        .is_some_and(|g| g.width != width || g.height != height);
+    if size_changed {
+        if let Some(old) = graphics.take() {
+            old.destroy();
+        }
+    }
+
+    if graphics.is_none() {
+        *graphics = PreviewGraphics::new(width, height);
+    }
+
+    let Some(graphics) = graphics.as_mut() else {
+        return;
+    };
+
+    libobs::gs_texrender_reset(graphics.texrender);
+    if !libobs::gs_texrender_begin(graphics.texrender, width, height) {
+        return;
+    }
+
+    // Opaque black, so the letterbox bars are black as well
+    let mut clear_color = MaybeUninit::<libobs::vec4>::zeroed().assume_init();
+    clear_color.__bindgen_anon_1.__bindgen_anon_1.w = 1.0;
+    libobs::gs_clear(libobs::GS_CLEAR_COLOR, &clear_color, 0.0, 0);
+
+    libobs::gs_blend_state_push();
+    libobs::gs_blend_function(
+        libobs::gs_blend_type_GS_BLEND_ONE,
+        libobs::gs_blend_type_GS_BLEND_ZERO,
+    );
+
+    state.target.render_letterboxed(width, height, None);
+
+    libobs::gs_blend_state_pop();
+    libobs::gs_texrender_end(graphics.texrender);
+
+    let texture = libobs::gs_texrender_get_texture(graphics.texrender);
+    if !texture.is_null() {
+        libobs::gs_stage_texture(graphics.stagesurface, texture);
+        graphics.staged = true;
+    }
+}
+
+/// Renders the main view, a scene or a source into a texture without a window and delivers
+/// the frames as RGBA pixels. Create it with `ObsContext::offscreen_preview`.
+///
+/// Frames are only rendered while there are subscribers. Rendering stops when the preview
+/// is dropped.
+#[derive(Debug, Clone)]
+pub struct ObsOffscreenPreview {
+    state: Arc<PreviewState>,
+    _guard: Arc<_ObsOffscreenPreviewDropGuard>,
+    pub(crate) runtime: ObsRuntime,
+}
+
+impl ObsOffscreenPreview {
+    pub(crate) fn new(
+        data: ObsOffscreenPreviewCreationData,
+        runtime: ObsRuntime,
+    ) -> Result<Self, ObsError> {
+        let ObsOffscreenPreviewCreationData {
+            width,
+            height,
+            max_fps,
+            target,
+        } = data;
+
+        // Only the newest frames are of interest, slow receivers skip older ones
+        let (sender, _) = broadcast::channel(2);
+        let showing_source = Sendable(unsafe { target.source_ptr() });
+
+        let state = Arc::new(PreviewState {
+            target,
+            settings: Mutex::new(PreviewSettings {
+                width,
+                height,
+                min_interval: min_interval(max_fps),
+                last_render: None,
+            }),
+            graphics: Mutex::new(None),
+            sender,
+        });
+
+        // The render callback owns one reference of the state, which is released by the drop guard
+        let param = Sendable(Arc::into_raw(state.clone()) as *mut c_void);
+        run_with_obs!(runtime, (param, showing_source), move || unsafe {
+            // Sources only update their content while they are shown
+            if !showing_source.is_null() {
+                libobs::obs_source_inc_showing(showing_source);
+            }
+
+            libobs::obs_add_main_rendered_callback(Some(render_offscreen_preview), param);
+        })?;
+
+        Ok(Self {
+            state,
+            _guard: Arc::new(_ObsOffscreenPreviewDropGuard {
+                param,
+                showing_source,
+                runtime: runtime.clone(),
+            }),
+            runtime,
+        })
+    }
+
+    /// Subscribes to the rendered frames.
+    ///
+    /// Receivers that can't keep up skip frames and get a `RecvError::Lagged` error.
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<ObsPreviewFrame>> {
+        self.state.sender.subscribe()
+    }
+
+    /// Sets the size of the rendered frames.
+    pub fn set_size(&self, width: u32, height: u32) -> Result<(), ObsError> {
+        let mut settings = self
+            .state
+            .settings
+            .lock()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))?;
+
+        settings.width = width;
+        settings.height = height;
+        Ok(())
+    }
+
+    pub fn get_size(&self) -> Result<(u32, u32), ObsError> {
+        let settings = self
+            .state
+            .settings
+            .lock()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))?;
+
+        Ok((settings.width, settings.height))
+    }
+
+    /// Sets the maximum frame rate of the preview, `None` renders every frame of OBS.
+    pub fn set_max_fps(&self, max_fps: Option<f64>) -> Result<(), ObsError> {
+        let mut settings = self
+            .state
+            .settings
+            .lock()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))?;
+
+        settings.min_interval = min_interval(max_fps);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct _ObsOffscreenPreviewDropGuard {
+    param: Sendable<*mut c_void>,
+    /// Null if the main view is rendered
+    showing_source: Sendable<*mut libobs::obs_source_t>,
+    pub(crate) runtime: ObsRuntime,
+}
+
+impl_obs_drop!(
+    _ObsOffscreenPreviewDropGuard,
+    (param, showing_source),
+    move || unsafe {
+        log::trace!("Removing offscreen preview callback {:?}...", param);
+        libobs::obs_remove_main_rendered_callback(Some(render_offscreen_preview), param);
+
+        let state = Arc::from_raw(param as *const PreviewState);
+
+        libobs::obs_enter_graphics();
+        if let Some(graphics) = state.graphics.lock().ok().and_then(|mut g| g.take()) {
+            graphics.destroy();
+        }
+        libobs::obs_leave_graphics();
+
+        if !showing_source.is_null() {
+            libobs::obs_source_dec_showing(showing_source);
+        }
+
+        // Dropping the target may release the last reference to a source, which must not happen
+        // on the OBS thread
+        Sendable(state)
+    }
+);
@@ -0,0 +1,95 @@
+use libobs::obs_scene_t;
+
+use crate::{scenes::get_item_boxes, utils::ObsString, Vec2};
+
+/// Draws outlines and resize handles around the selected items of the scene shown by a display.
+/// Items are selected with `ObsSceneRef::set_source_selected`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObsSelectionOverlay {
+    color: u32,
+    handle_size: f32,
+    show_handles: bool,
+}
+
+impl Default for ObsSelectionOverlay {
+    fn default() -> Self {
+        Self {
+            color: 0xFFFF0000,
+            handle_size: 8.0,
+            show_handles: true,
+        }
+    }
+}
+
+impl ObsSelectionOverlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the color of the outlines and handles, red by default.
+    pub fn set_color(mut self, r: u8, g: u8, b: u8) -> Self {
+        self.color = 0xFF000000 | ((r as u32) << 16) | ((g as u32) << 8) | (b as u32);
+        self
+    }
+
+    /// Sets the size of the resize handles in display pixels.
+    pub fn set_handle_size(mut self, handle_size: f32) -> Self {
+        self.handle_size = handle_size;
+        self
+    }
+
+    pub fn set_show_handles(mut self, show_handles: bool) -> Self {
+        self.show_handles = show_handles;
+        self
+    }
+
+    /// Draws the overlay in canvas coordinates. `scale` is the size of a canvas pixel in
+    /// display pixels, so the handles have the same size regardless of the display size.
+    /// Must be called inside the graphics context.
+    pub(crate) unsafe fn draw(&self, scene: *mut obs_scene_t, scale: f32) {
+        let selected = get_item_boxes(scene)
+            .into_iter()
+            .filter(|item_box| item_box.selected)
+            .collect::<Vec<_>>();
+
+        if selected.is_empty() || scale <= 0.0 {
+            return;
+        }
+
+        let effect = libobs::obs_get_base_effect(libobs::obs_base_effect_OBS_EFFECT_SOLID);
+        let color_name = ObsString::new("color");
+        let color = libobs::gs_effect_get_param_by_name(effect, color_name.as_ptr().0);
+        libobs::gs_effect_set_color(color, self.color);
+
+        let half_handle = self.handle_size / scale / 2.0;
+        let technique = ObsString::new("Solid");
+        while libobs::gs_effect_loop(effect, technique.as_ptr().0) {
+            for item_box in &selected {
+                let corners = item_box.transform.corners();
+
+                libobs::gs_render_start(true);
+                for corner in corners.iter().chain(std::iter::once(&corners[0])) {
+                    libobs::gs_vertex2f(corner.x, corner.y);
+                }
+                libobs::gs_render_stop(libobs::gs_draw_mode_GS_LINESTRIP);
+
+                if !self.show_handles {
+                    continue;
+                }
+
+                for handle in item_box.transform.handles() {
+                    draw_square(handle, half_handle);
+                }
+            }
+        }
+    }
+}
+
+unsafe fn draw_square(center: Vec2, half_size: f32) {
+    libobs::gs_render_start(true);
+    libobs::gs_vertex2f(center.x - half_size, center.y - half_size);
+    libobs::gs_vertex2f(center.x + half_size, center.y - half_size);
+    libobs::gs_vertex2f(center.x - half_size, center.y + half_size);
+    libobs::gs_vertex2f(center.x + half_size, center.y + half_size);
+    libobs::gs_render_stop(libobs::gs_draw_mode_GS_TRISTRIP);
+}
@@ -0,0 +1,216 @@
+use std::mem::MaybeUninit;
+
+use libobs::{obs_source_t, obs_video_info};
+
+use crate::{scenes::ObsSceneRef, sources::ObsSourceRef, Vec2};
+
+use super::ObsSelectionOverlay;
+
+/// What a display or an offscreen preview renders.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum RenderTarget<'a> {
+    /// The program output of the main view
+    #[default]
+    Main,
+    /// A scene, regardless of whether it is the active scene
+    Scene(&'a ObsSceneRef),
+    /// A single source, for example a camera
+    Source(&'a ObsSourceRef),
+}
+
+/// Owned version of `RenderTarget`, keeps the scene or source alive while it is rendered.
+#[derive(Debug, Clone, Default)]
+pub(crate) enum OwnedRenderTarget {
+    #[default]
+    Main,
+    Scene(ObsSceneRef),
+    Source(ObsSourceRef),
+}
+
+impl From<RenderTarget<'_>> for OwnedRenderTarget {
+    fn from(target: RenderTarget<'_>) -> Self {
+        match target {
+            RenderTarget::Main => Self::Main,
+            RenderTarget::Scene(scene) => Self::Scene(scene.clone()),
+            RenderTarget::Source(source) => Self::Source(source.clone()),
+        }
+    }
+}
+
+impl OwnedRenderTarget {
+    /// Returns the source that is rendered, or null for the main view.
+    pub(crate) unsafe fn source_ptr(&self) -> *mut obs_source_t {
+        match self {
+            Self::Main => std::ptr::null_mut(),
+            Self::Scene(scene) => libobs::obs_scene_get_source(scene.as_ptr().0),
+            Self::Source(source) => source.source.0,
+        }
+    }
+
+    /// Returns the size of the target in canvas pixels, which is the base resolution for
+    /// the main view.
+    pub(crate) unsafe fn base_size(&self) -> (u32, u32) {
+        get_base_size(self.source_ptr())
+    }
+
+    /// Renders the target into a viewport of the given size, keeping the aspect ratio of the
+    /// target. The overlay is drawn on top if the target shows a scene.
+    /// Must be called inside the graphics context.
+    pub(crate) unsafe fn render_letterboxed(
+        &self,
+        width: u32,
+        height: u32,
+        overlay: Option<&ObsSelectionOverlay>,
+    ) {
+        let (base_width, base_height) = self.base_size();
+        if base_width == 0 || base_height == 0 {
+            return;
+        }
+
+        let (x, y, scale) = get_scale_and_center_pos(base_width, base_height, width, height);
+
+        libobs::gs_viewport_push();
+        libobs::gs_projection_push();
+
+        libobs::gs_ortho(
+            0.0f32,
+            base_width as f32,
+            0.0f32,
+            base_height as f32,
+            -100.0f32,
+            100.0f32,
+        );
+        libobs::gs_set_viewport(
+            x,
+            y,
+            (base_width as f32 * scale) as i32,
+            (base_height as f32 * scale) as i32,
+        );
+
+        let source = self.source_ptr();
+        if source.is_null() {
+            libobs::obs_render_main_texture_src_color_only();
+        } else {
+            libobs::obs_source_video_render(source);
+        }
+
+        if let Some(overlay) = overlay {
+            self.render_overlay(overlay, scale);
+        }
+
+        libobs::gs_projection_pop();
+        libobs::gs_viewport_pop();
+    }
+
+    unsafe fn render_overlay(&self, overlay: &ObsSelectionOverlay, scale: f32) {
+        match self {
+            Self::Main => {
+                // The main view shows whatever is set to the first output channel
+                let source = libobs::obs_get_output_source(0);
+                if source.is_null() {
+                    return;
+                }
+
+                let scene = libobs::obs_scene_from_source(source);
+                if !scene.is_null() {
+                    overlay.draw(scene, scale);
+                }
+
+                libobs::obs_source_release(source);
+            }
+            Self::Scene(scene) => overlay.draw(scene.as_ptr().0, scale),
+            Self::Source(_) => {}
+        }
+    }
+}
+
+/// Position, size and scale of a display together with the size of what it renders.
+/// Used to map between points of the window the display is in and canvas coordinates.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisplayGeometry {
+    pub(crate) pos: (i32, i32),
+    pub(crate) size: (u32, u32),
+    pub(crate) scale: f32,
+    pub(crate) base_size: (u32, u32),
+}
+
+impl DisplayGeometry {
+    /// Returns the position of the letterboxed target in display pixels and its scale.
+    fn letterbox(&self) -> (f32, f32, f32) {
+        let (x, y, scale) =
+            get_scale_and_center_pos(self.base_size.0, self.base_size.1, self.size.0, self.size.1);
+
+        (x as f32, y as f32, scale)
+    }
+
+    pub(crate) fn preview_to_canvas(&self, point: Vec2) -> Vec2 {
+        let (x, y, scale) = self.letterbox();
+
+        let display_x = point.x * self.scale - self.pos.0 as f32;
+        let display_y = point.y * self.scale - self.pos.1 as f32;
+
+        Vec2::new((display_x - x) / scale, (display_y - y) / scale)
+    }
+
+    pub(crate) fn canvas_to_preview(&self, point: Vec2) -> Vec2 {
+        let (x, y, scale) = self.letterbox();
+
+        let display_x = point.x * scale + x;
+        let display_y = point.y * scale + y;
+
+        Vec2::new(
+            (display_x + self.pos.0 as f32) / self.scale,
+            (display_y + self.pos.1 as f32) / self.scale,
+        )
+    }
+}
+
+/// Returns the size of the source in canvas pixels, or the base resolution if the source is null.
+pub(crate) unsafe fn get_base_size(source: *mut obs_source_t) -> (u32, u32) {
+    if !source.is_null() {
+        return (
+            libobs::obs_source_get_width(source),
+            libobs::obs_source_get_height(source),
+        );
+    }
+
+    let mut ovi = MaybeUninit::<obs_video_info>::uninit();
+    if !libobs::obs_get_video_info(ovi.as_mut_ptr()) {
+        return (0, 0);
+    }
+
+    let ovi = ovi.assume_init();
+    (ovi.base_width, ovi.base_height)
+}
+
+/// Computes the position and scale to fit a target of the base size into a window, keeping
+/// the aspect ratio. The remaining space is split evenly on both sides.
+///
+/// Returns `(x, y, scale)` in window pixels.
+pub(crate) fn get_scale_and_center_pos(
+    base_width: u32,
+    base_height: u32,
+    window_width: u32,
+    window_height: u32,
+) -> (i32, i32, f32) {
+    if base_width == 0 || base_height == 0 {
+        return (0, 0, 1.0);
+    }
+
+    let window_aspect = window_width as f64 / window_height as f64;
+    let base_aspect = base_width as f64 / base_height as f64;
+
+    let scale = if window_aspect > base_aspect {
+        window_height as f64 / base_height as f64
+    } else {
+        window_width as f64 / base_width as f64
+    };
+
+    let scaled_width = (base_width as f64 * scale) as i64;
+    let scaled_height = (base_height as f64 * scale) as i64;
+
+    let x = (window_width as i64 - scaled_width) / 2;
+    let y = (window_height as i64 - scaled_height) / 2;
+
+    (x as i32, y as i32, scale as f32)
+}
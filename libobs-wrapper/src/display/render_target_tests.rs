@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{get_scale_and_center_pos, DisplayGeometry};
+    use crate::Vec2;
+
+    #[test]
+    fn test_scale_and_center_pos() {
+        // Same aspect ratio fills the whole window
+        assert_eq!(get_scale_and_center_pos(1920, 1080, 960, 540), (0, 0, 0.5));
+
+        // Pillarbox for wider windows
+        assert_eq!(
+            get_scale_and_center_pos(1920, 1080, 1000, 540),
+            (20, 0, 0.5)
+        );
+
+        // Letterbox for taller windows
+        assert_eq!(get_scale_and_center_pos(1920, 1080, 960, 640), (0, 50, 0.5));
+
+        assert_eq!(get_scale_and_center_pos(0, 0, 960, 640), (0, 0, 1.0));
+    }
+
+    #[test]
+    fn test_preview_to_canvas() {
+        // A 1000x540 display at (100, 50) in a window with a scale of 2, showing a 1920x1080 canvas
+        let geometry = DisplayGeometry {
+            pos: (100, 50),
+            size: (1000, 540),
+            scale: 2.0,
+            base_size: (1920, 1080),
+        };
+
+        // The canvas is pillarboxed by 20 pixels on each side
+        let top_left = geometry.preview_to_canvas(Vec2::new(60.0, 25.0));
+        assert_eq!((*top_left.x(), *top_left.y()), (0.0, 0.0));
+
+        let center = geometry.preview_to_canvas(Vec2::new(300.0, 160.0));
+        assert_eq!((*center.x(), *center.y()), (960.0, 540.0));
+
+        let outside = geometry.preview_to_canvas(Vec2::new(55.0, 25.0));
+        assert!(*outside.x() < 0.0);
+
+        let back = geometry.canvas_to_preview(Vec2::new(960.0, 540.0));
+        assert_eq!((*back.x(), *back.y()), (300.0, 160.0));
+    }
+}
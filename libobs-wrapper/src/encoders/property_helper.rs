@@ -149,4 +149,8 @@ impl ObsPropertyObjectPrivate for StructName {
             Sendable(libobs::obs_get_encoder_properties(id_ptr))
         })
     }
+
+    fn get_properties_runtime(&self) -> Option<ObsRuntime> {
+        Some(self.runtime.clone())
+    }
 }
@@ -220,6 +220,66 @@ pub enum ObsEncoderType {
     Audio = libobs::obs_encoder_type_OBS_ENCODER_AUDIO,
 }
 
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// The type of a value stored in `ObsData`.
+pub enum ObsDataType {
+    Null = libobs::obs_data_type_OBS_DATA_NULL,
+    String = libobs::obs_data_type_OBS_DATA_STRING,
+    Number = libobs::obs_data_type_OBS_DATA_NUMBER,
+    Boolean = libobs::obs_data_type_OBS_DATA_BOOLEAN,
+    Object = libobs::obs_data_type_OBS_DATA_OBJECT,
+    Array = libobs::obs_data_type_OBS_DATA_ARRAY,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// The type of a number stored in `ObsData`.
+pub enum ObsDataNumberType {
+    Invalid = libobs::obs_data_number_type_OBS_DATA_NUM_INVALID,
+    Int = libobs::obs_data_number_type_OBS_DATA_NUM_INT,
+    Double = libobs::obs_data_number_type_OBS_DATA_NUM_DOUBLE,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// The icon OBS uses for a source type, for example in the "Add source" menu.
+pub enum ObsIconType {
+    Unknown = libobs::obs_icon_type_OBS_ICON_TYPE_UNKNOWN,
+    Image = libobs::obs_icon_type_OBS_ICON_TYPE_IMAGE,
+    Color = libobs::obs_icon_type_OBS_ICON_TYPE_COLOR,
+    Slideshow = libobs::obs_icon_type_OBS_ICON_TYPE_SLIDESHOW,
+    AudioInput = libobs::obs_icon_type_OBS_ICON_TYPE_AUDIO_INPUT,
+    AudioOutput = libobs::obs_icon_type_OBS_ICON_TYPE_AUDIO_OUTPUT,
+    DesktopCapture = libobs::obs_icon_type_OBS_ICON_TYPE_DESKTOP_CAPTURE,
+    WindowCapture = libobs::obs_icon_type_OBS_ICON_TYPE_WINDOW_CAPTURE,
+    GameCapture = libobs::obs_icon_type_OBS_ICON_TYPE_GAME_CAPTURE,
+    Camera = libobs::obs_icon_type_OBS_ICON_TYPE_CAMERA,
+    Text = libobs::obs_icon_type_OBS_ICON_TYPE_TEXT,
+    Media = libobs::obs_icon_type_OBS_ICON_TYPE_MEDIA,
+    Browser = libobs::obs_icon_type_OBS_ICON_TYPE_BROWSER,
+    Custom = libobs::obs_icon_type_OBS_ICON_TYPE_CUSTOM,
+    ProcessAudioOutput = libobs::obs_icon_type_OBS_ICON_TYPE_PROCESS_AUDIO_OUTPUT,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// The playback state of a source with media controls, such as the media source.
+pub enum ObsMediaState {
+    None = libobs::obs_media_state_OBS_MEDIA_STATE_NONE,
+    Playing = libobs::obs_media_state_OBS_MEDIA_STATE_PLAYING,
+    Opening = libobs::obs_media_state_OBS_MEDIA_STATE_OPENING,
+    Buffering = libobs::obs_media_state_OBS_MEDIA_STATE_BUFFERING,
+    Paused = libobs::obs_media_state_OBS_MEDIA_STATE_PAUSED,
+    Stopped = libobs::obs_media_state_OBS_MEDIA_STATE_STOPPED,
+    Ended = libobs::obs_media_state_OBS_MEDIA_STATE_ENDED,
+    Error = libobs::obs_media_state_OBS_MEDIA_STATE_ERROR,
+}
+
 #[cfg_attr(target_os = "windows", repr(i32))]
 #[cfg_attr(not(target_os = "windows"), repr(u32))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
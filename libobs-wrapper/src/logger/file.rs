@@ -1,35 +1,296 @@
-use std::{fs::File, path::Path};
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
-use chrono::Local;
+use chrono::{DateTime, Local};
 
 use super::ObsLogger;
 
+const LOG_PREFIX: &str = "obs-";
+const LOG_EXTENSION: &str = ".log";
+const COMPRESSED_EXTENSION: &str = ".log.gz";
+const LATEST_LOG: &str = "latest.log";
+
+/// Splits the name of a file created at `<time>` (formatted as `%Y-%m-%d-%H-%M-%S`, without
+/// prefix and extension) into its timestamp and the counter that is appended if multiple files
+/// were created within the same second. The counter is compared as number, so `<time>-10` is
+/// sorted after `<time>-2`, and the first file `<time>` has counter 0.
+pub(crate) fn timestamped_name_sort_key(stem: &str) -> (String, u32) {
+    // The timestamp itself contains 5 dashes, a counter is appended after a sixth one
+    match stem.rsplit_once('-') {
+        Some((time, counter)) if time.matches('-').count() == 5 => match counter.parse() {
+            Ok(counter) => (time.to_string(), counter),
+            Err(_) => (stem.to_string(), 0),
+        },
+        _ => (stem.to_string(), 0),
+    }
+}
+
+/// Options for the rotation of a `FileLogger` created with `FileLogger::from_dir_with_options`.
+///
+/// By default, logs are never rotated or deleted.
+#[derive(Debug, Clone, Default)]
+pub struct FileLoggerOptions {
+    max_size: Option<u64>,
+    max_age: Option<Duration>,
+    max_files: Option<usize>,
+    #[cfg(feature = "log-compression")]
+    compress: bool,
+    latest_log: bool,
+}
+
+impl FileLoggerOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new log file once the current one is larger than the given amount of bytes.
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    /// Starts a new log file once the current one is older than the given duration.
+    pub fn max_age(mut self, age: Duration) -> Self {
+        self.max_age = Some(age);
+        self
+    }
+
+    /// Deletes the oldest log files in the directory so at most `count` log files
+    /// (including the current one) are kept.
+    pub fn max_files(mut self, count: usize) -> Self {
+        self.max_files = Some(count.max(1));
+        self
+    }
+
+    /// Compresses log files with gzip once a new log file was started.
+    #[cfg(feature = "log-compression")]
+    pub fn compress(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    /// Keeps a copy of the current log file named `latest.log` in the log directory.
+    pub fn latest_log(mut self, latest_log: bool) -> Self {
+        self.latest_log = latest_log;
+        self
+    }
+}
+
 /// A logger that writes logs to a file
 #[derive(Debug)]
 pub struct FileLogger {
     file: File,
+    path: PathBuf,
+    /// The directory the logs are rotated in, `None` if the logger was created from a single file
+    dir: Option<PathBuf>,
+    options: FileLoggerOptions,
+    latest: Option<File>,
+    written: u64,
+    opened_at: DateTime<Local>,
 }
 
 impl FileLogger {
     pub fn from_dir(dir: &Path) -> anyhow::Result<Self> {
-        let current_local = Local::now();
-        let custom_format = current_local.format("%Y-%m-%d-%H-%M-%S");
+        Self::from_dir_with_options(dir, FileLoggerOptions::default())
+    }
 
-        Ok(Self {
-            file: File::create(dir.join(format!("obs-{}.log", custom_format)))?,
-        })
+    /// Creates a new log file in the given directory which is rotated based on the given options.
+    pub fn from_dir_with_options(dir: &Path, options: FileLoggerOptions) -> anyhow::Result<Self> {
+        let opened_at = Local::now();
+        let path = Self::next_log_path(dir, &opened_at);
+
+        let mut logger = Self {
+            file: File::create(&path)?,
+            path,
+            dir: Some(dir.to_path_buf()),
+            options,
+            latest: None,
+            written: 0,
+            opened_at,
+        };
+
+        if logger.options.latest_log {
+            logger.latest = Some(File::create(dir.join(LATEST_LOG))?);
+        }
+
+        logger.cleanup()?;
+        Ok(logger)
     }
 
     pub fn from_file(file: &Path) -> anyhow::Result<Self> {
         Ok(Self {
             file: File::create(file)?,
+            path: file.to_path_buf(),
+            dir: None,
+            options: FileLoggerOptions::default(),
+            latest: None,
+            written: 0,
+            opened_at: Local::now(),
         })
     }
+
+    /// Returns the path of the file that is currently written to.
+    pub fn current_path(&self) -> &Path {
+        &self.path
+    }
+
+    fn next_log_path(dir: &Path, time: &DateTime<Local>) -> PathBuf {
+        let custom_format = time.format("%Y-%m-%d-%H-%M-%S");
+        let mut path = dir.join(format!("{}{}{}", LOG_PREFIX, custom_format, LOG_EXTENSION));
+
+        // Multiple rotations within the same second need unique names
+        let mut counter = 1;
+        while path.exists() || Self::compressed_path(&path).exists() {
+            path = dir.join(format!(
+                "{}{}-{}{}",
+                LOG_PREFIX, custom_format, counter, LOG_EXTENSION
+            ));
+            counter += 1;
+        }
+
+        path
+    }
+
+    fn compressed_path(path: &Path) -> PathBuf {
+        let mut name = path.as_os_str().to_os_string();
+        name.push(".gz");
+        PathBuf::from(name)
+    }
+
+    fn should_rotate(&self) -> bool {
+        if self.dir.is_none() {
+            return false;
+        }
+
+        let too_large = self
+            .options
+            .max_size
+            .is_some_and(|max_size| self.written >= max_size);
+
+        let too_old = self.options.max_age.is_some_and(|max_age| {
+            let age = Local::now().signed_duration_since(self.opened_at);
+            age.to_std().is_ok_and(|age| age >= max_age)
+        });
+
+        too_large || too_old
+    }
+
+    fn rotate(&mut self) -> anyhow::Result<()> {
+        let dir = match &self.dir {
+            Some(dir) => dir.clone(),
+            None => return Ok(()),
+        };
+
+        self.file.flush()?;
+
+        let opened_at = Local::now();
+        let path = Self::next_log_path(&dir, &opened_at);
+
+        // The current file is kept if the new one can't be created
+        let file = File::create(&path)?;
+        let previous = std::mem::replace(&mut self.path, path);
+
+        self.file = file;
+        self.written = 0;
+        self.opened_at = opened_at;
+
+        if self.options.latest_log {
+            self.latest = Some(File::create(dir.join(LATEST_LOG))?);
+        }
+
+        #[cfg(feature = "log-compression")]
+        if self.options.compress {
+            Self::compress_file(&previous)?;
+        }
+
+        #[cfg(not(feature = "log-compression"))]
+        let _ = previous;
+
+        self.cleanup()
+    }
+
+    #[cfg(feature = "log-compression")]
+    fn compress_file(path: &Path) -> anyhow::Result<()> {
+        use flate2::{write::GzEncoder, Compression};
+
+        let mut input = File::open(path)?;
+        let output = File::create(Self::compressed_path(path))?;
+
+        let mut encoder = GzEncoder::new(output, Compression::default());
+        std::io::copy(&mut input, &mut encoder)?;
+        encoder.finish()?;
+
+        drop(input);
+        fs::remove_file(path)?;
+        Ok(())
+    }
+
+    /// Removes the oldest log files so at most `max_files` are kept.
+    fn cleanup(&self) -> anyhow::Result<()> {
+        let (dir, max_files) = match (&self.dir, self.options.max_files) {
+            (Some(dir), Some(max_files)) => (dir, max_files),
+            _ => return Ok(()),
+        };
+
+        let mut logs = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| *path != self.path)
+            .filter(|path| {
+                let name = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or_default();
+                name.starts_with(LOG_PREFIX)
+                    && (name.ends_with(LOG_EXTENSION) || name.ends_with(COMPRESSED_EXTENSION))
+            })
+            .collect::<Vec<_>>();
+
+        // Sorted by age, oldest first
+        logs.sort_by_key(|path| {
+            let name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default();
+            let stem = name
+                .trim_end_matches(COMPRESSED_EXTENSION)
+                .trim_end_matches(LOG_EXTENSION)
+                .trim_start_matches(LOG_PREFIX);
+
+            timestamped_name_sort_key(stem)
+        });
+
+        let retained = max_files - 1;
+        if logs.len() > retained {
+            for path in &logs[..logs.len() - retained] {
+                fs::remove_file(path)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl ObsLogger for FileLogger {
     fn log(&mut self, level: crate::enums::ObsLogLevel, msg: String) {
-        use std::io::Write;
-        writeln!(self.file, "[{:?}] {}", level, msg).unwrap();
+        if self.should_rotate() {
+            if let Err(e) = self.rotate() {
+                eprintln!("Failed to rotate log file: {:?}", e);
+            }
+        }
+
+        let line = format!("[{:?}] {}\n", level, msg);
+        self.file.write_all(line.as_bytes()).unwrap();
+        self.written += line.len() as u64;
+
+        if let Some(latest) = &mut self.latest {
+            if let Err(e) = latest.write_all(line.as_bytes()) {
+                eprintln!("Failed to write to {}: {:?}", LATEST_LOG, e);
+            }
+        }
     }
 }
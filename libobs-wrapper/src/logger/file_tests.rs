@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf, time::Duration};
+
+    use super::super::{FileLogger, FileLoggerOptions, ObsLogger};
+    use crate::enums::ObsLogLevel;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("libobs-wrapper-{}-{}", name, std::process::id()));
+
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn log_files(dir: &PathBuf) -> Vec<String> {
+        let mut files = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .filter(|name| name.starts_with("obs-"))
+            .collect::<Vec<_>>();
+
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn test_file_logger_without_rotation() {
+        let dir = test_dir("no-rotation");
+        let mut logger = FileLogger::from_dir(&dir).unwrap();
+        for _ in 0..100 {
+            logger.log(ObsLogLevel::Info, "message".to_string());
+        }
+
+        assert_eq!(log_files(&dir).len(), 1);
+        let content = fs::read_to_string(logger.current_path()).unwrap();
+        assert_eq!(content.lines().count(), 100);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_logger_rotates_by_size() {
+        let dir = test_dir("rotate-size");
+        let options = FileLoggerOptions::new().max_size(64).max_files(3);
+        let mut logger = FileLogger::from_dir_with_options(&dir, options).unwrap();
+
+        for i in 0..20 {
+            logger.log(ObsLogLevel::Info, format!("message number {}", i));
+        }
+
+        // Old files are deleted so only the newest ones are kept
+        assert_eq!(log_files(&dir).len(), 3);
+
+        let content = fs::read_to_string(logger.current_path()).unwrap();
+        assert!(content.contains("message number 19"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_logger_rotates_by_age() {
+        let dir = test_dir("rotate-age");
+        let options = FileLoggerOptions::new().max_age(Duration::from_millis(50));
+        let mut logger = FileLogger::from_dir_with_options(&dir, options).unwrap();
+        let first_path = logger.current_path().to_path_buf();
+
+        logger.log(ObsLogLevel::Info, "first".to_string());
+        logger.log(ObsLogLevel::Info, "second".to_string());
+        assert_eq!(logger.current_path(), first_path);
+
+        std::thread::sleep(Duration::from_millis(100));
+        logger.log(ObsLogLevel::Info, "third".to_string());
+
+        assert_ne!(logger.current_path(), first_path);
+        assert_eq!(log_files(&dir).len(), 2);
+
+        let first = fs::read_to_string(&first_path).unwrap();
+        assert_eq!(first.lines().count(), 2);
+        let current = fs::read_to_string(logger.current_path()).unwrap();
+        assert!(current.contains("third"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    // Removing a directory with open files only works on unix
+    #[cfg(unix)]
+    #[test]
+    fn test_file_logger_keeps_file_if_rotation_fails() {
+        let dir = test_dir("rotate-failure");
+        let options = FileLoggerOptions::new().max_size(1);
+        let mut logger = FileLogger::from_dir_with_options(&dir, options).unwrap();
+        let path = logger.current_path().to_path_buf();
+
+        logger.log(ObsLogLevel::Info, "first".to_string());
+        fs::remove_dir_all(&dir).unwrap();
+
+        // The new file can't be created, so the logger keeps writing to the current one
+        logger.log(ObsLogLevel::Info, "second".to_string());
+        assert_eq!(logger.current_path(), path);
+    }
+
+    #[test]
+    fn test_file_logger_cleanup_sorts_counters_numerically() {
+        let dir = test_dir("cleanup-order");
+        fs::write(dir.join("obs-2020-01-01-00-00-00.log"), "").unwrap();
+        for counter in 1..=11 {
+            let name = format!("obs-2020-01-01-00-00-00-{}.log", counter);
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let options = FileLoggerOptions::new().max_files(3);
+        let logger = FileLogger::from_dir_with_options(&dir, options).unwrap();
+
+        let current = logger
+            .current_path()
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+
+        // The newest rotations of the old timestamp are kept, not the ones sorted last as strings
+        let mut expected = vec![
+            "obs-2020-01-01-00-00-00-10.log".to_string(),
+            "obs-2020-01-01-00-00-00-11.log".to_string(),
+            current,
+        ];
+        expected.sort();
+        assert_eq!(log_files(&dir), expected);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_logger_latest_log() {
+        let dir = test_dir("latest");
+        let options = FileLoggerOptions::new().max_size(64).latest_log(true);
+        let mut logger = FileLogger::from_dir_with_options(&dir, options).unwrap();
+
+        for i in 0..10 {
+            logger.log(ObsLogLevel::Warning, format!("message number {}", i));
+        }
+
+        let latest = fs::read_to_string(dir.join("latest.log")).unwrap();
+        let current = fs::read_to_string(logger.current_path()).unwrap();
+        assert_eq!(latest, current);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "log-compression")]
+    #[test]
+    fn test_file_logger_compresses_old_logs() {
+        let dir = test_dir("compress");
+        let options = FileLoggerOptions::new().max_size(64).compress(true);
+        let mut logger = FileLogger::from_dir_with_options(&dir, options).unwrap();
+
+        for i in 0..10 {
+            logger.log(ObsLogLevel::Info, format!("message number {}", i));
+        }
+
+        let files = log_files(&dir);
+        let compressed = files.iter().filter(|f| f.ends_with(".log.gz")).count();
+        assert_eq!(compressed, files.len() - 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
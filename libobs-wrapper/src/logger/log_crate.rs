@@ -0,0 +1,55 @@
+use crate::enums::ObsLogLevel;
+
+use super::{split_module_prefix, ObsLogger};
+
+/// A logger that forwards OBS logs to the `log` crate.
+///
+/// The target of each record is `obs` followed by the module of the message if there is one,
+/// for example `obs::win-capture`. A custom target prefix can be set with `with_target`.
+#[derive(Debug)]
+pub struct LogCrateLogger {
+    target: String,
+}
+
+impl Default for LogCrateLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LogCrateLogger {
+    pub fn new() -> Self {
+        Self {
+            target: "obs".to_string(),
+        }
+    }
+
+    /// Sets the prefix of the target of each log record
+    pub fn with_target<T: Into<String>>(mut self, target: T) -> Self {
+        self.target = target.into();
+        self
+    }
+}
+
+impl From<ObsLogLevel> for log::Level {
+    fn from(level: ObsLogLevel) -> Self {
+        match level {
+            ObsLogLevel::Error => log::Level::Error,
+            ObsLogLevel::Warning => log::Level::Warn,
+            ObsLogLevel::Info => log::Level::Info,
+            ObsLogLevel::Debug => log::Level::Debug,
+        }
+    }
+}
+
+impl ObsLogger for LogCrateLogger {
+    fn log(&mut self, level: ObsLogLevel, msg: String) {
+        let (module, message) = split_module_prefix(&msg);
+        let target = match module {
+            Some(module) => format!("{}::{}", self.target, module),
+            None => self.target.clone(),
+        };
+
+        log::log!(target: target.as_str(), log::Level::from(level), "{}", message);
+    }
+}
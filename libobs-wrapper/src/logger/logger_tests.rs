@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        sync::{Arc, Mutex},
+    };
+
+    use super::super::{split_module_prefix, MultiLogger, ObsLogger};
+    use crate::{enums::ObsLogLevel, utils::StartupInfo};
+
+    #[derive(Debug, Default)]
+    struct CollectingLogger {
+        messages: Arc<Mutex<Vec<(ObsLogLevel, String)>>>,
+    }
+
+    impl ObsLogger for CollectingLogger {
+        fn log(&mut self, level: ObsLogLevel, msg: String) {
+            self.messages.lock().unwrap().push((level, msg));
+        }
+    }
+
+    #[test]
+    fn test_split_module_prefix() {
+        assert_eq!(
+            split_module_prefix("[obs-browser]: Version 2.0"),
+            (Some("obs-browser"), "Version 2.0")
+        );
+        assert_eq!(
+            split_module_prefix("[win-capture] hooked"),
+            (Some("win-capture"), "hooked")
+        );
+    }
+
+    #[test]
+    fn test_split_module_prefix_with_detail() {
+        let msg = "[game-capture: 'Game'] attempting to hook process";
+        assert_eq!(split_module_prefix(msg), (Some("game-capture"), msg));
+    }
+
+    #[test]
+    fn test_split_module_prefix_without_module() {
+        assert_eq!(split_module_prefix("no module"), (None, "no module"));
+        assert_eq!(split_module_prefix("[unclosed"), (None, "[unclosed"));
+        assert_eq!(split_module_prefix("[] empty"), (None, "[] empty"));
+    }
+
+    #[test]
+    fn test_multi_logger_fan_out() {
+        let first = CollectingLogger::default();
+        let second = CollectingLogger::default();
+        let first_messages = first.messages.clone();
+        let second_messages = second.messages.clone();
+
+        let mut logger = MultiLogger::new().with(first).with(second);
+        assert_eq!(logger.len(), 2);
+
+        logger.log(ObsLogLevel::Warning, "test".to_string());
+
+        let expected = vec![(ObsLogLevel::Warning, "test".to_string())];
+        assert_eq!(*first_messages.lock().unwrap(), expected);
+        assert_eq!(*second_messages.lock().unwrap(), expected);
+    }
+
+    /// A logger that is `Send` but not `Sync`
+    #[derive(Debug, Default)]
+    struct CountingLogger {
+        count: Cell<usize>,
+    }
+
+    impl ObsLogger for CountingLogger {
+        fn log(&mut self, _level: ObsLogLevel, _msg: String) {
+            self.count.set(self.count.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_multi_logger_accepts_non_sync_loggers() {
+        let mut logger = MultiLogger::new()
+            .with(CountingLogger::default())
+            .with_boxed(Box::new(CountingLogger::default()));
+
+        logger.log(ObsLogLevel::Info, "test".to_string());
+        assert_eq!(logger.len(), 2);
+
+        let _info = StartupInfo::new().set_logger(Box::new(logger));
+    }
+}
@@ -0,0 +1,257 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, TryLockError},
+};
+
+use chrono::{DateTime, Local};
+
+use crate::enums::ObsLogLevel;
+
+use super::{split_module_prefix, ObsLogger};
+
+/// A single log line stored by the `MemoryLogger`.
+#[derive(Debug, Clone, PartialEq, getters0::Getters)]
+#[skip_new]
+pub struct ObsLogRecord {
+    timestamp: DateTime<Local>,
+    level: ObsLogLevel,
+    module: Option<String>,
+    message: String,
+}
+
+impl ObsLogRecord {
+    pub fn new(
+        timestamp: DateTime<Local>,
+        level: ObsLogLevel,
+        module: Option<String>,
+        message: String,
+    ) -> Self {
+        Self {
+            timestamp,
+            level,
+            module,
+            message,
+        }
+    }
+}
+
+impl std::fmt::Display for ObsLogRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} [{:?}]",
+            self.timestamp.format("%Y-%m-%d %H:%M:%S%.3f"),
+            self.level
+        )?;
+
+        if let Some(module) = &self.module {
+            write!(f, " [{}]", module)?;
+        }
+
+        write!(f, " {}", self.message)
+    }
+}
+
+/// Filters the records of a `MemoryLogHandle`. All set conditions must match.
+#[derive(Debug, Clone, Default)]
+pub struct ObsLogQuery {
+    level: Option<ObsLogLevel>,
+    module: Option<String>,
+    since: Option<DateTime<Local>>,
+    until: Option<DateTime<Local>>,
+    limit: Option<usize>,
+}
+
+impl ObsLogQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only returns records that are at least as severe as the given level
+    /// (for example `Warning` returns warnings and errors).
+    pub fn level(mut self, level: ObsLogLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Only returns records of the given module (for example `win-capture`).
+    pub fn module<T: Into<String>>(mut self, module: T) -> Self {
+        self.module = Some(module.into());
+        self
+    }
+
+    /// Only returns records logged at or after the given time.
+    pub fn since(mut self, since: DateTime<Local>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Only returns records logged at or before the given time.
+    pub fn until(mut self, until: DateTime<Local>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Only returns the newest `limit` matching records.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn matches(&self, record: &ObsLogRecord) -> bool {
+        // Lower values are more severe in libobs
+        let level_matches = self
+            .level
+            .is_none_or(|level| record.level as i64 <= level as i64);
+
+        let module_matches = self
+            .module
+            .as_ref()
+            .is_none_or(|module| record.module.as_ref() == Some(module));
+
+        let since_matches = self.since.is_none_or(|since| record.timestamp >= since);
+        let until_matches = self.until.is_none_or(|until| record.timestamp <= until);
+
+        level_matches && module_matches && since_matches && until_matches
+    }
+}
+
+/// A logger that keeps the newest log records in memory.
+///
+/// The records can be read through a `MemoryLogHandle` while the logger is installed, so it is
+/// usually combined with other loggers using the `MultiLogger`:
+/// ```no_run
+/// use libobs_wrapper::logger::{ConsoleLogger, MemoryLogger, MultiLogger};
+///
+/// let memory = MemoryLogger::new(1000);
+/// let handle = memory.handle();
+///
+/// let logger = MultiLogger::new().with(ConsoleLogger::new()).with(memory);
+/// // Pass `logger` to `StartupInfo::set_logger` and use `handle` to read the logs
+/// ```
+#[derive(Debug)]
+pub struct MemoryLogger {
+    handle: MemoryLogHandle,
+}
+
+impl MemoryLogger {
+    /// Creates a new logger that keeps at most `capacity` records.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            handle: MemoryLogHandle {
+                records: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+                capacity,
+            },
+        }
+    }
+
+    /// Returns a handle to read the stored records.
+    pub fn handle(&self) -> MemoryLogHandle {
+        self.handle.clone()
+    }
+}
+
+impl ObsLogger for MemoryLogger {
+    fn log(&mut self, level: ObsLogLevel, msg: String) {
+        let (module, message) = split_module_prefix(&msg);
+        let record = ObsLogRecord::new(
+            Local::now(),
+            level,
+            module.map(|m| m.to_string()),
+            message.to_string(),
+        );
+
+        self.handle.push(record);
+    }
+}
+
+/// Gives access to the records of a `MemoryLogger`.
+#[derive(Debug, Clone)]
+pub struct MemoryLogHandle {
+    records: Arc<Mutex<VecDeque<ObsLogRecord>>>,
+    capacity: usize,
+}
+
+impl MemoryLogHandle {
+    fn push(&self, record: ObsLogRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut records = self.records.lock().unwrap();
+        while records.len() >= self.capacity {
+            records.pop_front();
+        }
+
+        records.push_back(record);
+    }
+
+    /// Returns the maximum amount of records that are kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns all stored records, oldest first.
+    pub fn records(&self) -> Vec<ObsLogRecord> {
+        self.records.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Returns the stored records matching the given query, oldest first.
+    pub fn query(&self, query: &ObsLogQuery) -> Vec<ObsLogRecord> {
+        let records = self.records.lock().unwrap();
+        Self::query_records(&records, query)
+    }
+
+    fn query_records(records: &VecDeque<ObsLogRecord>, query: &ObsLogQuery) -> Vec<ObsLogRecord> {
+        let mut result = records
+            .iter()
+            .rev()
+            .filter(|record| query.matches(record))
+            .take(query.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        result.reverse();
+        result
+    }
+
+    fn format_records(records: &[ObsLogRecord]) -> String {
+        records
+            .iter()
+            .map(|record| format!("{}\n", record))
+            .collect()
+    }
+
+    /// Exports the records matching the given query as text, one record per line.
+    pub fn export(&self, query: &ObsLogQuery) -> String {
+        Self::format_records(&self.query(query))
+    }
+
+    /// Like `export`, but returns `None` instead of waiting if the records are currently locked,
+    /// for example by the thread that is logging. Used where blocking could deadlock, such as
+    /// in a crash handler.
+    pub(crate) fn try_export(&self, query: &ObsLogQuery) -> Option<String> {
+        let records = match self.records.try_lock() {
+            Ok(records) => records,
+            Err(TryLockError::Poisoned(e)) => e.into_inner(),
+            Err(TryLockError::WouldBlock) => return None,
+        };
+
+        Some(Self::format_records(&Self::query_records(&records, query)))
+    }
+
+    /// Writes the records matching the given query to the given file.
+    pub fn export_to_file(
+        &self,
+        query: &ObsLogQuery,
+        path: &std::path::Path,
+    ) -> anyhow::Result<()> {
+        std::fs::write(path, self.export(query))?;
+        Ok(())
+    }
+
+    /// Removes all stored records.
+    pub fn clear(&self) {
+        self.records.lock().unwrap().clear();
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Local};
+
+    use super::super::{MemoryLogger, ObsLogQuery, ObsLogger};
+    use crate::enums::ObsLogLevel;
+
+    #[test]
+    fn test_memory_logger_capacity() {
+        let mut logger = MemoryLogger::new(3);
+        let handle = logger.handle();
+
+        for i in 0..5 {
+            logger.log(ObsLogLevel::Info, format!("message {}", i));
+        }
+
+        let messages = handle
+            .records()
+            .iter()
+            .map(|record| record.message().clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(messages, vec!["message 2", "message 3", "message 4"]);
+    }
+
+    #[test]
+    fn test_memory_logger_extracts_module() {
+        let mut logger = MemoryLogger::new(10);
+        let handle = logger.handle();
+
+        logger.log(ObsLogLevel::Info, "[win-capture] hooked".to_string());
+
+        let records = handle.records();
+        assert_eq!(records[0].module().as_deref(), Some("win-capture"));
+        assert_eq!(records[0].message(), "hooked");
+    }
+
+    #[test]
+    fn test_memory_logger_query() {
+        let mut logger = MemoryLogger::new(10);
+        let handle = logger.handle();
+
+        logger.log(ObsLogLevel::Debug, "[a] debug".to_string());
+        logger.log(ObsLogLevel::Warning, "[a] warning".to_string());
+        logger.log(ObsLogLevel::Error, "[b] error".to_string());
+        logger.log(ObsLogLevel::Info, "[b] info".to_string());
+
+        let warnings = handle.query(&ObsLogQuery::new().level(ObsLogLevel::Warning));
+        assert_eq!(warnings.len(), 2);
+
+        let module_b = handle.query(&ObsLogQuery::new().module("b"));
+        assert_eq!(module_b.len(), 2);
+
+        let latest = handle.query(&ObsLogQuery::new().limit(1));
+        assert_eq!(latest[0].message(), "info");
+
+        let future = handle.query(&ObsLogQuery::new().since(Local::now() + Duration::hours(1)));
+        assert!(future.is_empty());
+    }
+
+    #[test]
+    fn test_memory_logger_export() {
+        let mut logger = MemoryLogger::new(10);
+        let handle = logger.handle();
+
+        logger.log(ObsLogLevel::Error, "[b] error".to_string());
+        logger.log(ObsLogLevel::Info, "no module".to_string());
+
+        let export = handle.export(&ObsLogQuery::new());
+        let lines = export.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("[Error] [b] error"));
+        assert!(lines[1].ends_with("[Info] no module"));
+
+        handle.clear();
+        assert!(handle.records().is_empty());
+    }
+}
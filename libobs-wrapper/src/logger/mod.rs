@@ -1,7 +1,25 @@
 mod console;
 mod file;
+mod log_crate;
+mod memory;
+mod multi;
+#[cfg(feature = "tracing-logger")]
+mod tracing_logger;
 pub use console::ConsoleLogger;
-pub use file::FileLogger;
+pub(crate) use file::timestamped_name_sort_key;
+pub use file::{FileLogger, FileLoggerOptions};
+pub use log_crate::LogCrateLogger;
+pub use memory::{MemoryLogHandle, MemoryLogger, ObsLogQuery, ObsLogRecord};
+pub use multi::MultiLogger;
+#[cfg(feature = "tracing-logger")]
+pub use tracing_logger::TracingLogger;
+
+#[cfg(test)]
+mod file_tests;
+#[cfg(test)]
+mod logger_tests;
+#[cfg(test)]
+mod memory_tests;
 
 use std::{fmt::Debug, os::raw::c_void, sync::Mutex};
 
@@ -52,3 +70,37 @@ pub(crate) fn internal_log_global(level: ObsLogLevel, msg: String) {
     let mut logger = LOGGER.lock().unwrap();
     logger.log(level, msg);
 }
+
+/// Splits the `[module]` prefix OBS puts in front of most log messages from the message.
+///
+/// If the prefix contains more than the module name (for example `[game-capture: 'Game']`),
+/// only the part before the colon is returned as module and the message is kept as is, so
+/// no information is lost.
+pub fn split_module_prefix(msg: &str) -> (Option<&str>, &str) {
+    let rest = match msg.strip_prefix('[') {
+        Some(rest) => rest,
+        None => return (None, msg),
+    };
+
+    let end = match rest.find(']') {
+        Some(end) => end,
+        None => return (None, msg),
+    };
+
+    let prefix = &rest[..end];
+    let (module, has_detail) = match prefix.split_once(':') {
+        Some((module, _)) => (module.trim(), true),
+        None => (prefix.trim(), false),
+    };
+
+    if module.is_empty() {
+        return (None, msg);
+    }
+
+    if has_detail {
+        return (Some(module), msg);
+    }
+
+    let message = rest[end + 1..].trim_start_matches(':').trim_start();
+    (Some(module), message)
+}
@@ -0,0 +1,60 @@
+use crate::enums::ObsLogLevel;
+
+use super::ObsLogger;
+
+/// A logger that forwards every message to multiple loggers.
+///
+/// ```no_run
+/// use libobs_wrapper::{
+///     logger::{ConsoleLogger, LogCrateLogger, MultiLogger},
+///     utils::StartupInfo,
+/// };
+///
+/// let logger = MultiLogger::new()
+///     .with(ConsoleLogger::new())
+///     .with(LogCrateLogger::new());
+///
+/// let info = StartupInfo::new().set_logger(Box::new(logger));
+/// ```
+#[derive(Debug, Default)]
+pub struct MultiLogger {
+    loggers: Vec<Box<dyn ObsLogger + Send>>,
+}
+
+impl MultiLogger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a logger that should receive all messages
+    pub fn with<T: ObsLogger + Send + 'static>(mut self, logger: T) -> Self {
+        self.loggers.push(Box::new(logger));
+        self
+    }
+
+    /// Adds an already boxed logger that should receive all messages
+    pub fn with_boxed(mut self, logger: Box<dyn ObsLogger + Send>) -> Self {
+        self.loggers.push(logger);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.loggers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.loggers.is_empty()
+    }
+}
+
+impl ObsLogger for MultiLogger {
+    fn log(&mut self, level: ObsLogLevel, msg: String) {
+        if let Some((last, rest)) = self.loggers.split_last_mut() {
+            for logger in rest {
+                logger.log(level, msg.clone());
+            }
+
+            last.log(level, msg);
+        }
+    }
+}
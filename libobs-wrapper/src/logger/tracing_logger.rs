@@ -0,0 +1,55 @@
+use crate::enums::ObsLogLevel;
+
+use super::{split_module_prefix, ObsLogger};
+
+/// A logger that forwards OBS logs to `tracing` as events with the target `obs`.
+///
+/// The module of the message is recorded in the `module` field.
+#[derive(Debug)]
+pub struct TracingLogger {
+    _private: (),
+}
+
+impl Default for TracingLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TracingLogger {
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl From<ObsLogLevel> for tracing::Level {
+    fn from(level: ObsLogLevel) -> Self {
+        match level {
+            ObsLogLevel::Error => tracing::Level::ERROR,
+            ObsLogLevel::Warning => tracing::Level::WARN,
+            ObsLogLevel::Info => tracing::Level::INFO,
+            ObsLogLevel::Debug => tracing::Level::DEBUG,
+        }
+    }
+}
+
+impl ObsLogger for TracingLogger {
+    fn log(&mut self, level: ObsLogLevel, msg: String) {
+        let (module, message) = split_module_prefix(&msg);
+        let module = module.unwrap_or_default();
+
+        // The level of `tracing::event!` has to be known at compile time
+        match level {
+            ObsLogLevel::Error => {
+                tracing::error!(target: "obs", module, "{}", message)
+            }
+            ObsLogLevel::Warning => {
+                tracing::warn!(target: "obs", module, "{}", message)
+            }
+            ObsLogLevel::Info => tracing::info!(target: "obs", module, "{}", message),
+            ObsLogLevel::Debug => {
+                tracing::debug!(target: "obs", module, "{}", message)
+            }
+        }
+    }
+}
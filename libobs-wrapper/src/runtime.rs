@@ -396,7 +396,12 @@ impl ObsRuntime {
 
         let mut log_callback = LOGGER.lock().map_err(|_e| ObsError::MutexFailure)?;
 
-        *log_callback = info.logger.take().expect("Logger can never be null");
+        *log_callback = info
+            .logger
+            .take()
+            .expect("Logger can never be null")
+            .into_inner()
+            .map_err(|_e| ObsError::MutexFailure)?;
         drop(log_callback);
 
         // Locale will only be used internally by
@@ -1,7 +1,13 @@
+mod transform;
+pub use transform::*;
+
+#[cfg(test)]
+mod transform_tests;
+
 use std::sync::{Arc, RwLock};
 
 use getters0::Getters;
-use libobs::{obs_scene_t, obs_source_t};
+use libobs::{obs_scene_t, obs_sceneitem_t, obs_source_t};
 
 use crate::{
     impl_obs_drop, impl_signal_manager, run_with_obs,
@@ -24,6 +30,49 @@ impl_obs_drop!(_SceneDropGuard, (scene), move || unsafe {
     libobs::obs_scene_release(scene);
 });
 
+/// A visible item of a scene with its bounding box.
+#[derive(Debug, Clone)]
+pub(crate) struct SceneItemBox {
+    pub(crate) item: Sendable<*mut obs_sceneitem_t>,
+    pub(crate) transform: ObsBoxTransform,
+    pub(crate) selected: bool,
+}
+
+/// Returns the boxes of the visible items of the scene from bottom to top.
+/// Items of groups are not included. The item pointers are not referenced.
+pub(crate) unsafe fn get_item_boxes(scene: *mut obs_scene_t) -> Vec<SceneItemBox> {
+    unsafe extern "C" fn enum_item(
+        _scene: *mut obs_scene_t,
+        item: *mut obs_sceneitem_t,
+        param: *mut std::ffi::c_void,
+    ) -> bool {
+        if !libobs::obs_sceneitem_visible(item) {
+            return true;
+        }
+
+        let mut transform: libobs::matrix4 = std::mem::zeroed();
+        libobs::obs_sceneitem_get_box_transform(item, &mut transform);
+
+        let boxes = &mut *(param as *mut Vec<SceneItemBox>);
+        boxes.push(SceneItemBox {
+            item: Sendable(item),
+            transform: transform.into(),
+            selected: libobs::obs_sceneitem_selected(item),
+        });
+
+        true
+    }
+
+    let mut boxes: Vec<SceneItemBox> = Vec::new();
+    libobs::obs_scene_enum_items(
+        scene,
+        Some(enum_item),
+        &mut boxes as *mut _ as *mut std::ffi::c_void,
+    );
+
+    boxes
+}
+
 #[derive(Debug, Clone, Getters)]
 #[skip_new]
 pub struct ObsSceneRef {
@@ -248,6 +297,88 @@ impl ObsSceneRef {
         Ok(())
     }
 
+    /// Returns the bounding box of the source in canvas coordinates.
+    ///
+    /// libobs updates the transform of an item on the next video tick, so after moving, scaling
+    /// or rotating it, this returns the previous box for up to one frame.
+    pub fn get_source_box_transform(
+        &self,
+        source: &ObsSourceRef,
+    ) -> Result<ObsBoxTransform, ObsError> {
+        let scene_item = source.scene_item.clone();
+        let Some(scene_item_ptr) = scene_item else {
+            return Err(ObsError::SourceNotFound);
+        };
+
+        let transform = run_with_obs!(self.runtime, (scene_item_ptr), move || unsafe {
+            let mut transform: libobs::matrix4 = std::mem::zeroed();
+            libobs::obs_sceneitem_get_box_transform(scene_item_ptr, &mut transform);
+            ObsBoxTransform::from(transform)
+        })?;
+
+        Ok(transform)
+    }
+
+    /// Marks the source as selected. Selected sources are outlined by the selection overlay
+    /// of displays showing this scene.
+    pub fn set_source_selected(
+        &self,
+        source: &ObsSourceRef,
+        selected: bool,
+    ) -> Result<(), ObsError> {
+        let scene_item = source.scene_item.clone();
+        let Some(scene_item_ptr) = scene_item else {
+            return Err(ObsError::SourceNotFound);
+        };
+
+        run_with_obs!(self.runtime, (scene_item_ptr), move || unsafe {
+            libobs::obs_sceneitem_select(scene_item_ptr, selected);
+        })
+    }
+
+    pub fn is_source_selected(&self, source: &ObsSourceRef) -> Result<bool, ObsError> {
+        let scene_item = source.scene_item.clone();
+        let Some(scene_item_ptr) = scene_item else {
+            return Err(ObsError::SourceNotFound);
+        };
+
+        run_with_obs!(self.runtime, (scene_item_ptr), move || unsafe {
+            libobs::obs_sceneitem_selected(scene_item_ptr)
+        })
+    }
+
+    /// Returns the topmost visible source whose bounding box contains the point in canvas
+    /// coordinates. Use `ObsDisplayRef::preview_to_canvas` to convert mouse positions.
+    ///
+    /// Only sources added through this scene are returned, other items are skipped. Like
+    /// `get_source_box_transform`, the boxes of items lag one frame behind after they are moved.
+    pub fn item_at(&self, point: Vec2) -> Result<Option<ObsSourceRef>, ObsError> {
+        let scene_ptr = self.scene.clone();
+        let boxes = run_with_obs!(self.runtime, (scene_ptr), move || unsafe {
+            get_item_boxes(scene_ptr)
+        })?;
+
+        let sources = self
+            .sources
+            .read()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))?;
+
+        let source = boxes
+            .iter()
+            .rev()
+            .filter(|item_box| item_box.transform.contains(point))
+            .find_map(|item_box| {
+                sources.iter().find(|s| {
+                    s.scene_item
+                        .as_ref()
+                        .is_some_and(|i| i.0 == item_box.item.0)
+                })
+            })
+            .cloned();
+
+        Ok(source)
+    }
+
     pub fn as_ptr(&self) -> Sendable<*mut obs_scene_t> {
         Sendable(self.scene.0)
     }
@@ -0,0 +1,95 @@
+use crate::Vec2;
+
+/// The bounding box of a scene item in canvas coordinates, including its position, rotation,
+/// scale and bounds.
+///
+/// A point `(u, v)` of the unit square is mapped to `origin + u * x_axis + v * y_axis`.
+#[derive(Debug, Clone, Copy)]
+pub struct ObsBoxTransform {
+    origin: Vec2,
+    x_axis: Vec2,
+    y_axis: Vec2,
+}
+
+impl From<libobs::matrix4> for ObsBoxTransform {
+    fn from(raw: libobs::matrix4) -> Self {
+        let (x, y, t) = unsafe {
+            (
+                raw.x.__bindgen_anon_1.__bindgen_anon_1,
+                raw.y.__bindgen_anon_1.__bindgen_anon_1,
+                raw.t.__bindgen_anon_1.__bindgen_anon_1,
+            )
+        };
+
+        Self {
+            origin: Vec2::new(t.x, t.y),
+            x_axis: Vec2::new(x.x, x.y),
+            y_axis: Vec2::new(y.x, y.y),
+        }
+    }
+}
+
+impl ObsBoxTransform {
+    pub fn new(origin: Vec2, x_axis: Vec2, y_axis: Vec2) -> Self {
+        Self {
+            origin,
+            x_axis,
+            y_axis,
+        }
+    }
+
+    /// Maps a point of the unit square to canvas coordinates.
+    pub fn apply(&self, u: f32, v: f32) -> Vec2 {
+        Vec2::new(
+            self.origin.x + u * self.x_axis.x + v * self.y_axis.x,
+            self.origin.y + u * self.x_axis.y + v * self.y_axis.y,
+        )
+    }
+
+    /// Maps a point in canvas coordinates to the unit square of the box, or `None` if the box
+    /// has no area.
+    pub fn invert(&self, point: Vec2) -> Option<(f32, f32)> {
+        let det = self.x_axis.x * self.y_axis.y - self.y_axis.x * self.x_axis.y;
+        if det.abs() < f32::EPSILON {
+            return None;
+        }
+
+        let dx = point.x - self.origin.x;
+        let dy = point.y - self.origin.y;
+
+        let u = (dx * self.y_axis.y - self.y_axis.x * dy) / det;
+        let v = (self.x_axis.x * dy - dx * self.x_axis.y) / det;
+        Some((u, v))
+    }
+
+    /// Whether the point in canvas coordinates lies inside the box.
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.invert(point)
+            .is_some_and(|(u, v)| (0.0..=1.0).contains(&u) && (0.0..=1.0).contains(&v))
+    }
+
+    /// The corners in canvas coordinates, starting top left and going clockwise.
+    pub fn corners(&self) -> [Vec2; 4] {
+        [
+            self.apply(0.0, 0.0),
+            self.apply(1.0, 0.0),
+            self.apply(1.0, 1.0),
+            self.apply(0.0, 1.0),
+        ]
+    }
+
+    /// The positions of the resize handles in canvas coordinates: the corners and the centers
+    /// of the edges.
+    pub fn handles(&self) -> [Vec2; 8] {
+        [
+            self.apply(0.0, 0.0),
+            self.apply(0.5, 0.0),
+            self.apply(1.0, 0.0),
+            self.apply(1.0, 0.5),
+            self.apply(1.0, 1.0),
+            self.apply(0.5, 1.0),
+            self.apply(0.0, 1.0),
+            self.apply(0.0, 0.5),
+        ]
+    }
+}
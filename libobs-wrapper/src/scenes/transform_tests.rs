@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use super::super::ObsBoxTransform;
+    use crate::Vec2;
+
+    #[test]
+    fn test_box_transform_contains() {
+        // A 200x100 box at (50, 20)
+        let transform = ObsBoxTransform::new(
+            Vec2::new(50.0, 20.0),
+            Vec2::new(200.0, 0.0),
+            Vec2::new(0.0, 100.0),
+        );
+
+        assert!(transform.contains(Vec2::new(50.0, 20.0)));
+        assert!(transform.contains(Vec2::new(150.0, 70.0)));
+        assert!(!transform.contains(Vec2::new(49.0, 70.0)));
+        assert!(!transform.contains(Vec2::new(150.0, 121.0)));
+
+        let (u, v) = transform.invert(Vec2::new(150.0, 70.0)).unwrap();
+        assert!((u - 0.5).abs() < 1e-5);
+        assert!((v - 0.5).abs() < 1e-5);
+
+        let handles = transform.handles();
+        assert_eq!(*handles[4].x(), 250.0);
+        assert_eq!(*handles[4].y(), 120.0);
+    }
+
+    #[test]
+    fn test_box_transform_rotated() {
+        // A 100x100 box rotated by 90 degrees around its origin at (100, 0)
+        let transform = ObsBoxTransform::new(
+            Vec2::new(100.0, 0.0),
+            Vec2::new(0.0, 100.0),
+            Vec2::new(-100.0, 0.0),
+        );
+
+        assert!(transform.contains(Vec2::new(50.0, 50.0)));
+        assert!(!transform.contains(Vec2::new(150.0, 50.0)));
+
+        let empty = ObsBoxTransform::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 100.0),
+        );
+        assert!(!empty.contains(Vec2::new(0.0, 0.0)));
+    }
+}
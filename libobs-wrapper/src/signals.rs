@@ -1,3 +1,19 @@
+use std::{collections::HashMap, ffi::c_void, sync::Mutex};
+
+use crate::{
+    data::CallData,
+    run_with_obs,
+    runtime::ObsRuntime,
+    unsafe_send::Sendable,
+    utils::{ObsError, ObsString},
+};
+
+mod global;
+pub use global::*;
+
+#[cfg(test)]
+mod dynamic_tests;
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __signals_impl_primitive_handler {
@@ -223,9 +239,15 @@ macro_rules! impl_signal_manager {
                 let _ = senders.send(res);
             })*
 
+            lazy_static::lazy_static! {
+                /// Counts the signal managers per object, as multiple wrappers can exist for the same object
+                static ref [<$name:snake:upper _REFS>]: std::sync::Mutex<std::collections::HashMap<$crate::unsafe_send::SendableComp<$ptr>, usize>> = std::sync::Mutex::new(std::collections::HashMap::new());
+            }
+
             #[derive(Debug)]
             pub struct $name {
                 pointer: $crate::unsafe_send::SendableComp<$ptr>,
+                dynamic: $crate::signals::DynamicSignals,
                 runtime: $crate::runtime::ObsRuntime
             }
 
@@ -234,6 +256,23 @@ macro_rules! impl_signal_manager {
                     use $crate::{utils::ObsString, unsafe_send::SendableComp};
                     let pointer =  SendableComp(ptr.0);
 
+                    let refs = [<$name:snake:upper _REFS>].lock();
+                    if refs.is_err() {
+                        return Err($crate::utils::ObsError::LockError("Failed to acquire lock for signal manager references".to_string()));
+                    }
+
+                    let mut refs = refs.unwrap();
+                    let count = refs.entry(pointer.clone()).or_insert(0);
+                    *count += 1;
+                    if *count > 1 {
+                        // The signals of this object are already connected
+                        return Ok(Self {
+                            pointer,
+                            dynamic: Default::default(),
+                            runtime
+                        });
+                    }
+
                     $(
                         let senders = [<$signal_name:snake:upper _SENDERS>].clone();
                         let senders = senders.write();
@@ -263,10 +302,23 @@ macro_rules! impl_signal_manager {
 
                     Ok(Self {
                         pointer,
+                        dynamic: Default::default(),
                         runtime
                     })
                 }
 
+                /// Subscribes to a signal by its name. This can be used for signals that have no
+                /// typed `on_*` method, for example signals of third-party plugins.
+                /// The parameters of the signal can be read from the received `CallData`.
+                pub fn connect_dynamic(&self, signal: &str) -> Result<tokio::sync::broadcast::Receiver<$crate::data::CallData>, $crate::utils::ObsError> {
+                    let pointer = self.pointer.clone();
+                    let handler = $crate::run_with_obs!(self.runtime, (pointer), move || {
+                        $crate::unsafe_send::Sendable(($handler_getter)(pointer))
+                    })?;
+
+                    self.dynamic.connect(handler, signal, &self.runtime)
+                }
+
                 $(
                     $(#[$attr])*
                     pub fn [<on_ $signal_name:snake>](&self) -> Result<tokio::sync::broadcast::Receiver<[<__Private $signal_name:camel Type >]>, $crate::utils::ObsError> {
@@ -289,6 +341,29 @@ macro_rules! impl_signal_manager {
                 fn drop(&mut self) {
                     log::trace!("Dropping signal manager {}...", stringify!($name));
 
+                    if let Err(e) = self.dynamic.disconnect_all(&self.runtime) {
+                        log::warn!("Failed to disconnect dynamic signals of {}: {}", stringify!($name), e);
+                    }
+
+                    let refs = [<$name:snake:upper _REFS>].lock();
+                    if refs.is_err() {
+                        log::warn!("Failed to acquire lock for signal manager references during drop");
+                        return;
+                    }
+
+                    let mut refs = refs.unwrap();
+                    if let Some(count) = refs.get_mut(&self.pointer) {
+                        *count -= 1;
+                        if *count > 0 {
+                            // Other signal managers of this object are still alive
+                            return;
+                        }
+
+                        refs.remove(&self.pointer);
+                    }
+
+                    drop(refs);
+
                     #[allow(unused_variables)]
                     let ptr = self.pointer.clone();
                     #[allow(unused_variables)]
@@ -336,3 +411,124 @@ macro_rules! impl_signal_manager {
         }
     };
 }
+
+/// A signal connected by name at runtime, see `connect_dynamic` on the signal managers.
+struct DynamicSignal {
+    handler: Sendable<*mut libobs::signal_handler_t>,
+    /// Boxed, so the pointer passed to the signal handler stays valid
+    sender: Box<tokio::sync::broadcast::Sender<CallData>>,
+}
+
+/// Keeps track of the signals a signal manager connected by name.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct DynamicSignals {
+    signals: Mutex<HashMap<String, DynamicSignal>>,
+}
+
+impl std::fmt::Debug for DynamicSignals {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = self
+            .signals
+            .lock()
+            .map(|signals| signals.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        f.debug_struct("DynamicSignals")
+            .field("signals", &names)
+            .finish()
+    }
+}
+
+unsafe extern "C" fn dynamic_signal_handler(param: *mut c_void, calldata: *mut libobs::calldata_t) {
+    let sender = &*(param as *const tokio::sync::broadcast::Sender<CallData>);
+
+    // Nobody is listening, so there is no need to copy the calldata
+    if sender.receiver_count() == 0 {
+        return;
+    }
+
+    let _ = sender.send(CallData::from_raw(calldata));
+}
+
+impl DynamicSignals {
+    /// Subscribes to the signal with the given name of the given signal handler.
+    /// The signal is connected on the first subscription.
+    pub fn connect(
+        &self,
+        handler: Sendable<*mut libobs::signal_handler_t>,
+        signal: &str,
+        runtime: &ObsRuntime,
+    ) -> Result<tokio::sync::broadcast::Receiver<CallData>, ObsError> {
+        let mut signals = self.signals.lock().map_err(|_| {
+            ObsError::LockError("Failed to acquire lock for dynamic signals".to_string())
+        })?;
+
+        if let Some(existing) = signals.get(signal) {
+            return Ok(existing.sender.subscribe());
+        }
+
+        let (tx, rx) = tokio::sync::broadcast::channel(16);
+        let sender = Box::new(tx);
+        let param = Sendable(sender.as_ref() as *const _ as *mut c_void);
+        let signal_name = ObsString::new(signal);
+        let signal_ptr = signal_name.as_ptr();
+
+        run_with_obs!(runtime, (handler, param, signal_ptr), move || unsafe {
+            libobs::signal_handler_connect(
+                handler,
+                signal_ptr,
+                Some(dynamic_signal_handler),
+                param,
+            );
+        })?;
+
+        signals.insert(signal.to_string(), DynamicSignal { handler, sender });
+        Ok(rx)
+    }
+
+    /// Disconnects all signals. Must be called before this struct is dropped.
+    pub fn disconnect_all(&self, runtime: &ObsRuntime) -> Result<(), ObsError> {
+        let mut signals = self.signals.lock().map_err(|_| {
+            ObsError::LockError("Failed to acquire lock for dynamic signals".to_string())
+        })?;
+
+        Self::disconnect_signals(signals.drain(), |name, signal| {
+            let handler = signal.handler.clone();
+            let param = Sendable(signal.sender.as_ref() as *const _ as *mut c_void);
+            let signal_name = ObsString::new(name);
+            let signal_ptr = signal_name.as_ptr();
+
+            run_with_obs!(runtime, (handler, param, signal_ptr), move || unsafe {
+                libobs::signal_handler_disconnect(
+                    handler,
+                    signal_ptr,
+                    Some(dynamic_signal_handler),
+                    param,
+                );
+            })
+        })
+    }
+
+    /// Disconnects every signal with the given function, even if disconnecting one of them
+    /// fails. Returns the first error.
+    fn disconnect_signals<I, F>(signals: I, mut disconnect: F) -> Result<(), ObsError>
+    where
+        I: Iterator<Item = (String, DynamicSignal)>,
+        F: FnMut(&str, &DynamicSignal) -> Result<(), ObsError>,
+    {
+        let mut first_error = None;
+        for (name, signal) in signals {
+            if let Err(e) = disconnect(&name, &signal) {
+                // The handler might still be called with the sender, so it must not be freed
+                std::mem::forget(signal.sender);
+                first_error.get_or_insert(e);
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use tokio::sync::broadcast::{self, error::TryRecvError};
+
+    use super::super::{DynamicSignal, DynamicSignals};
+    use crate::{data::CallData, unsafe_send::Sendable, utils::ObsError};
+
+    fn signal(name: &str) -> ((String, DynamicSignal), broadcast::Receiver<CallData>) {
+        let (tx, rx) = broadcast::channel(1);
+        let signal = DynamicSignal {
+            handler: Sendable(std::ptr::null_mut()),
+            sender: Box::new(tx),
+        };
+
+        ((name.to_string(), signal), rx)
+    }
+
+    #[test]
+    fn test_disconnect_continues_after_failure() {
+        let (first, mut first_rx) = signal("first");
+        let (failing, mut failing_rx) = signal("failing");
+        let (last, mut last_rx) = signal("last");
+        let (failing_again, _failing_again_rx) = signal("failing_again");
+
+        let mut disconnected = Vec::new();
+        let result = DynamicSignals::disconnect_signals(
+            vec![first, failing, last, failing_again].into_iter(),
+            |name, _signal| {
+                disconnected.push(name.to_string());
+                if name.starts_with("failing") {
+                    return Err(ObsError::InvocationError(name.to_string()));
+                }
+
+                Ok(())
+            },
+        );
+
+        // Every signal is disconnected and the first error is returned
+        assert_eq!(
+            disconnected,
+            vec!["first", "failing", "last", "failing_again"]
+        );
+        assert!(matches!(result, Err(ObsError::InvocationError(name)) if name == "failing"));
+
+        // Disconnected senders are freed, the ones that might still be called are kept alive
+        assert_eq!(first_rx.try_recv().unwrap_err(), TryRecvError::Closed);
+        assert_eq!(last_rx.try_recv().unwrap_err(), TryRecvError::Closed);
+        assert_eq!(failing_rx.try_recv().unwrap_err(), TryRecvError::Empty);
+    }
+
+    #[test]
+    fn test_disconnect_without_failure() {
+        let (first, mut first_rx) = signal("first");
+
+        let result = DynamicSignals::disconnect_signals(vec![first].into_iter(), |_, _| Ok(()));
+        assert!(result.is_ok());
+        assert_eq!(first_rx.try_recv().unwrap_err(), TryRecvError::Closed);
+    }
+}
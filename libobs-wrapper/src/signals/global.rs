@@ -0,0 +1,134 @@
+//! Signals of the global libobs signal handler (`obs_get_signal_handler`), which are emitted for
+//! every object, including objects created by plugins or by loading a scene collection.
+//!
+//! Pointers in the signals are only guaranteed to be valid while the signal is emitted.
+
+use crate::{impl_signal_manager, unsafe_send::Sendable};
+
+impl_signal_manager!(|_handler| unsafe { libobs::obs_get_signal_handler() }, ObsGlobalSignals for ObsContext<*mut libobs::signal_handler_t>, [
+    "source_create": {struct SourceCreateSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    /// The source is being destroyed, so it must not be referenced anymore.
+    "source_destroy": {struct SourceDestroySignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_remove": {struct SourceRemoveSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_update": {struct SourceUpdateSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_save": {struct SourceSaveSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_load": {struct SourceLoadSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_activate": {struct SourceActivateSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_deactivate": {struct SourceDeactivateSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_show": {struct SourceShowSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_hide": {struct SourceHideSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_audio_activate": {struct SourceAudioActivateSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_audio_deactivate": {struct SourceAudioDeactivateSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_filter_add": {struct SourceFilterAddSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+            filter: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_filter_remove": {struct SourceFilterRemoveSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+            filter: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_rename": {struct SourceRenameSignal {
+        new_name: String,
+        prev_name: String;
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_volume": {struct SourceVolumeSignal {
+        volume: f64;
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_transition_start": {struct SourceTransitionStartSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_transition_video_stop": {struct SourceTransitionVideoStopSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    "source_transition_stop": {struct SourceTransitionStopSignal {
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+        }
+    }},
+    /// `source` is null if the channel was cleared, `prev_source` is null if the channel was empty.
+    "channel_change": {struct ChannelChangeSignal {
+        channel: i64;
+        POINTERS {
+            source: *mut libobs::obs_source_t,
+            prev_source: *mut libobs::obs_source_t,
+        }
+    }},
+    "hotkey_layout_change": {},
+    "hotkey_register": {struct HotkeyRegisterSignal {
+        POINTERS {
+            key: *mut libobs::obs_hotkey_t,
+        }
+    }},
+    "hotkey_unregister": {struct HotkeyUnregisterSignal {
+        POINTERS {
+            key: *mut libobs::obs_hotkey_t,
+        }
+    }},
+    "hotkey_bindings_changed": {struct HotkeyBindingsChangedSignal {
+        POINTERS {
+            key: *mut libobs::obs_hotkey_t,
+        }
+    }},
+]);
@@ -1,6 +1,8 @@
-use crate::{data::ObsObjectBuilder, scenes::ObsSceneRef, utils::ObsError};
+use crate::{
+    context::ObsContext, data::ObsObjectBuilder, run_with_obs, scenes::ObsSceneRef, utils::ObsError,
+};
 
-use super::ObsSourceRef;
+use super::{ObsFilterRef, ObsSourceRef};
 
 pub trait ObsSourceBuilder: ObsObjectBuilder {
     fn add_to_scene(self, scene: &mut ObsSceneRef) -> Result<ObsSourceRef, ObsError>
@@ -11,3 +13,25 @@ pub trait ObsSourceBuilder: ObsObjectBuilder {
         scene.add_source(s)
     }
 }
+
+pub trait ObsFilterBuilder: ObsObjectBuilder {
+    /// Creates the filter and appends it to the filters of the given source.
+    fn add_to_source(
+        self,
+        context: &mut ObsContext,
+        source: &ObsSourceRef,
+    ) -> Result<ObsFilterRef, ObsError>
+    where
+        Self: Sized,
+    {
+        let filter = context.obs_filter(self.build()?)?;
+
+        let source_ptr = source.source.clone();
+        let filter_ptr = filter.source.clone();
+        run_with_obs!(source.runtime, (source_ptr, filter_ptr), move || unsafe {
+            libobs::obs_source_filter_add(source_ptr, filter_ptr);
+        })?;
+
+        Ok(filter)
+    }
+}
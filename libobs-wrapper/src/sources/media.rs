@@ -0,0 +1,107 @@
+//! Playback controls for sources that support them, such as the media source (`ffmpeg_source`)
+//! or the VLC source.
+use std::time::Duration;
+
+use num_traits::FromPrimitive;
+
+use crate::{enums::ObsMediaState, run_with_obs, utils::ObsError};
+
+use super::ObsSourceRef;
+
+/// Controls the playback of a media source.
+///
+/// Sources without media controls ignore these calls and report `ObsMediaState::None`.
+/// Use `ObsSourceOutputFlags::has_media_controls` of the source type to check whether a source
+/// can be controlled.
+pub trait ObsMediaControls {
+    /// Resumes the playback if it is paused.
+    fn play(&self) -> Result<(), ObsError>;
+
+    /// Pauses the playback at the current position.
+    fn pause(&self) -> Result<(), ObsError>;
+
+    /// Stops the playback. Playing again starts from the beginning.
+    fn stop(&self) -> Result<(), ObsError>;
+
+    /// Restarts the playback from the beginning.
+    fn restart(&self) -> Result<(), ObsError>;
+
+    /// Seeks to the given position of the media.
+    fn seek(&self, time: Duration) -> Result<(), ObsError>;
+
+    /// Returns the current playback position.
+    fn get_time(&self) -> Result<Duration, ObsError>;
+
+    /// Returns the duration of the media, or `Duration::ZERO` if it is unknown (e.g. for streams).
+    fn get_duration(&self) -> Result<Duration, ObsError>;
+
+    /// Returns the playback state of the media: `Opening` or `Buffering` while loading, then
+    /// `Playing`, `Paused`, `Stopped`, `Ended` or `Error`. Sources without media controls
+    /// (e.g. a color source) return `ObsMediaState::None`, as do unknown states.
+    fn get_state(&self) -> Result<ObsMediaState, ObsError>;
+}
+
+impl ObsMediaControls for ObsSourceRef {
+    fn play(&self) -> Result<(), ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_media_play_pause(source_ptr, false);
+        })
+    }
+
+    fn pause(&self) -> Result<(), ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_media_play_pause(source_ptr, true);
+        })
+    }
+
+    fn stop(&self) -> Result<(), ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_media_stop(source_ptr);
+        })
+    }
+
+    fn restart(&self) -> Result<(), ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_media_restart(source_ptr);
+        })
+    }
+
+    fn seek(&self, time: Duration) -> Result<(), ObsError> {
+        let source_ptr = self.source.clone();
+        let ms = i64::try_from(time.as_millis()).unwrap_or(i64::MAX);
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_media_set_time(source_ptr, ms);
+        })
+    }
+
+    fn get_time(&self) -> Result<Duration, ObsError> {
+        let source_ptr = self.source.clone();
+        let ms = run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_media_get_time(source_ptr)
+        })?;
+
+        Ok(Duration::from_millis(ms.max(0) as u64))
+    }
+
+    fn get_duration(&self) -> Result<Duration, ObsError> {
+        let source_ptr = self.source.clone();
+        let ms = run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_media_get_duration(source_ptr)
+        })?;
+
+        Ok(Duration::from_millis(ms.max(0) as u64))
+    }
+
+    fn get_state(&self) -> Result<ObsMediaState, ObsError> {
+        let source_ptr = self.source.clone();
+        let state = run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_media_get_state(source_ptr)
+        })?;
+
+        Ok(ObsMediaState::from_i64(state as i64).unwrap_or(ObsMediaState::None))
+    }
+}
@@ -1,10 +1,12 @@
 mod builder;
 pub use builder::*;
+mod media;
+pub use media::*;
 
 use libobs::{obs_scene_item, obs_source_t};
 
 use crate::{
-    data::{immutable::ImmutableObsData, ObsData},
+    data::{call_proc, immutable::ImmutableObsData, CallData, ObsData},
     impl_obs_drop, impl_signal_manager, run_with_obs,
     runtime::ObsRuntime,
     unsafe_send::Sendable,
@@ -90,6 +92,67 @@ impl ObsSourceRef {
         })
     }
 
+    /// Wraps an existing source. This takes ownership of one reference of the source,
+    /// so the source is released when the last clone of the returned wrapper is dropped.
+    pub(crate) fn from_raw(
+        source: Sendable<*mut obs_source_t>,
+        runtime: ObsRuntime,
+    ) -> Result<Self, ObsError> {
+        if source.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        // Created first, so the reference is released if anything below fails
+        let guard = Arc::new(_ObsSourceGuard {
+            source: source.clone(),
+            runtime: runtime.clone(),
+        });
+
+        let source_ptr = source.clone();
+        let (id, name, settings, hotkey_data) =
+            run_with_obs!(runtime, (source_ptr), move || unsafe {
+                let id = libobs::obs_source_get_id(source_ptr);
+                let name = libobs::obs_source_get_name(source_ptr);
+                let id = if id.is_null() {
+                    String::new()
+                } else {
+                    std::ffi::CStr::from_ptr(id).to_string_lossy().to_string()
+                };
+                let name = if name.is_null() {
+                    String::new()
+                } else {
+                    std::ffi::CStr::from_ptr(name).to_string_lossy().to_string()
+                };
+
+                (
+                    id,
+                    name,
+                    Sendable(libobs::obs_source_get_settings(source_ptr)),
+                    Sendable(libobs::obs_hotkeys_save_source(source_ptr)),
+                )
+            })?;
+
+        let settings = ImmutableObsData::from_raw(settings, runtime.clone());
+        let hotkey_data = if hotkey_data.0.is_null() {
+            ImmutableObsData::new(&runtime)?
+        } else {
+            ImmutableObsData::from_raw(hotkey_data, runtime.clone())
+        };
+
+        let signals = ObsSourceSignals::new(&source, runtime.clone())?;
+        Ok(Self {
+            source,
+            id: id.into(),
+            name: name.into(),
+            settings: Arc::new(settings),
+            hotkey_data: Arc::new(hotkey_data),
+            _guard: guard,
+            scene_item: None,
+            runtime,
+            signal_manager: Arc::new(signals),
+        })
+    }
+
     pub fn settings(&self) -> &ImmutableObsData {
         &self.settings
     }
@@ -106,9 +169,34 @@ impl ObsSourceRef {
         self.id.to_string()
     }
 
+    /// Returns the uuid libobs assigned to this source, which stays the same when the source is
+    /// renamed.
+    pub fn uuid(&self) -> Result<String, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            let uuid = libobs::obs_source_get_uuid(source_ptr);
+            if uuid.is_null() {
+                return String::new();
+            }
+
+            std::ffi::CStr::from_ptr(uuid).to_string_lossy().to_string()
+        })
+    }
+
     pub fn signal_manager(&self) -> Arc<ObsSourceSignals> {
         self.signal_manager.clone()
     }
+
+    /// Calls the procedure with the given name of this source and returns the calldata the
+    /// procedure has written to.
+    pub fn call_proc(&self, name: &str, calldata: CallData) -> Result<CallData, ObsError> {
+        let source_ptr = self.source.clone();
+        let handler = run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            Sendable(libobs::obs_source_get_proc_handler(source_ptr))
+        })?;
+
+        call_proc(handler, name, calldata, &self.runtime)
+    }
 }
 
 impl ObsUpdatable for ObsSourceRef {
@@ -214,7 +302,7 @@ impl_signal_manager!(|ptr| unsafe { libobs::obs_source_get_signal_handler(ptr) }
     "media_next": {},
     "media_previous": {},
     /// This is just for sources that are of the `game-capture`, `window-capture` or `win-wasapi` type. Other sources will never emit this signal.
-    //TODO Add support for the `linux-capture` type as it does not contain the `title` field (its 'name' instead)
+    /// For `linux-capture` sources, which use different fields, use `connect_dynamic("hooked")` instead.
     "hooked": {struct HookedSignal {
         title: String,
         class: String,
@@ -224,7 +312,6 @@ impl_signal_manager!(|ptr| unsafe { libobs::obs_source_get_signal_handler(ptr) }
         }
     }},
     /// This is just for sources that are of the `game-capture`, `window-capture` or `win-wasapi` type. Other sources will never emit this signal.
-    //TODO Add support for the `linux-capture` type as it does not contain the `title` field (its 'name' instead)
     "unhooked": {struct UnhookedSignal {
         POINTERS {
             source: *mut libobs::obs_source_t,
@@ -29,6 +29,8 @@ pub enum ObsError {
     OutputPauseFailure(Option<String>),
     OutputNotFound,
     SourceNotFound,
+    /// The property with the given name does not exist
+    PropertyNotFound,
     /// Error converting a string between Rust and OBS
     StringConversionError,
 
@@ -36,11 +38,15 @@ pub enum ObsError {
     DisplayCreationError(String),
 
     OutputSaveBufferFailure(String),
+    /// The procedure with the given name doesn't exist
+    ProcCallFailure(String),
 
     /// The obs thread couldn't be called
     InvocationError(String),
 
     JsonParseError,
+    /// Failed to convert a Rust value from or to `ObsData`
+    SerializationError(String),
     /// Couldn't get the sender of the signal
     NoSenderError,
     NoAvailableEncoders,
@@ -71,9 +77,12 @@ impl Display for ObsError {
             ObsError::OutputNotFound => write!(f, "Output not found."),
             ObsError::DisplayCreationError(e) => write!(f, "Native error from the Windows API when creating a display: {:?}", e),
             ObsError::OutputSaveBufferFailure(e) => write!(f, "Couldn't save output buffer: {:?}", e),
+            ObsError::ProcCallFailure(name) => write!(f, "The procedure {:?} doesn't exist", name),
             ObsError::SourceNotFound => write!(f, "Source not found."),
+            ObsError::PropertyNotFound => write!(f, "Property not found."),
             ObsError::InvocationError(e) => write!(f, "The obs thread couldn't be called: {:?}", e),
             ObsError::JsonParseError => write!(f, "Failed to parse JSON data."),
+            ObsError::SerializationError(e) => write!(f, "Failed to convert value from or to ObsData: {:?}", e),
             ObsError::NoSenderError => write!(f, "Couldn't get the sender of the signal."),
             ObsError::NoAvailableEncoders => write!(f, "No available encoders found."),
             ObsError::OutputPauseFailure(s) => write!(f, "Output failed to pause. Error is {:?}", s),
@@ -1,3 +1,5 @@
+use std::sync::Mutex;
+
 use crate::{
     context::ObsContext,
     data::{audio::ObsAudioInfo, video::ObsVideoInfo},
@@ -12,8 +14,9 @@ pub struct StartupInfo {
     pub(crate) startup_paths: StartupPaths,
     pub(crate) obs_video_info: ObsVideoInfo,
     pub(crate) obs_audio_info: ObsAudioInfo,
-    // Option because logger is taken when creating
-    pub(crate) logger: Option<Box<dyn ObsLogger + Sync + Send>>,
+    // Option because logger is taken when creating. The mutex keeps the info `Sync` for
+    // loggers that are only `Send`.
+    pub(crate) logger: Option<Mutex<Box<dyn ObsLogger + Send>>>,
 }
 
 impl StartupInfo {
@@ -38,8 +41,8 @@ impl StartupInfo {
     }
 
     #[cfg_attr(coverage_nightly, coverage(off))]
-    pub fn set_logger(mut self, logger: Box<dyn ObsLogger + Sync + Send>) -> Self {
-        self.logger = Some(logger);
+    pub fn set_logger(mut self, logger: Box<dyn ObsLogger + Send>) -> Self {
+        self.logger = Some(Mutex::new(logger));
         self
     }
 
@@ -55,7 +58,7 @@ impl Default for StartupInfo {
             startup_paths: StartupPaths::default(),
             obs_video_info: ObsVideoInfo::default(),
             obs_audio_info: ObsAudioInfo::default(),
-            logger: Some(Box::new(ConsoleLogger::new())),
+            logger: Some(Mutex::new(Box::new(ConsoleLogger::new()))),
         }
     }
 }
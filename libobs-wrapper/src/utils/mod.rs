@@ -1,6 +1,7 @@
 mod error;
 mod info;
 pub(crate) mod initialization;
+pub(crate) mod object_types;
 mod obs_string;
 mod path;
 pub mod traits;
@@ -16,6 +17,7 @@ use std::ffi::CStr;
 pub use error::*;
 pub use info::*;
 use libobs::obs_module_failure_info;
+pub use object_types::*;
 pub use obs_string::*;
 pub use path::*;
 
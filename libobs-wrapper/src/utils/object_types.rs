@@ -0,0 +1,272 @@
+use std::{ffi::CStr, os::raw::c_char};
+
+use getters0::Getters;
+use num_traits::FromPrimitive;
+
+use crate::{
+    enums::{ObsEncoderType, ObsIconType},
+    run_with_obs,
+    runtime::ObsRuntime,
+    utils::{ObsError, ObsString},
+};
+
+/// The output flags of a source type (`OBS_SOURCE_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObsSourceOutputFlags(u32);
+
+impl ObsSourceOutputFlags {
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub fn contains(&self, flags: u32) -> bool {
+        self.0 & flags == flags
+    }
+
+    pub fn is_video(&self) -> bool {
+        self.contains(libobs::OBS_SOURCE_VIDEO)
+    }
+
+    pub fn is_audio(&self) -> bool {
+        self.contains(libobs::OBS_SOURCE_AUDIO)
+    }
+
+    pub fn is_async(&self) -> bool {
+        self.contains(libobs::OBS_SOURCE_ASYNC)
+    }
+
+    pub fn is_custom_draw(&self) -> bool {
+        self.contains(libobs::OBS_SOURCE_CUSTOM_DRAW)
+    }
+
+    pub fn is_interactive(&self) -> bool {
+        self.contains(libobs::OBS_SOURCE_INTERACTION)
+    }
+
+    pub fn is_composite(&self) -> bool {
+        self.contains(libobs::OBS_SOURCE_COMPOSITE)
+    }
+
+    pub fn has_media_controls(&self) -> bool {
+        self.contains(libobs::OBS_SOURCE_CONTROLLABLE_MEDIA)
+    }
+
+    /// Deprecated and obsolete types should not be shown to the user.
+    pub fn is_deprecated(&self) -> bool {
+        self.contains(libobs::OBS_SOURCE_DEPRECATED)
+            || self.contains(libobs::OBS_SOURCE_CAP_OBSOLETE)
+    }
+}
+
+/// The output flags of an output type (`OBS_OUTPUT_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObsOutputFlags(u32);
+
+impl ObsOutputFlags {
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub fn contains(&self, flags: u32) -> bool {
+        self.0 & flags == flags
+    }
+
+    pub fn is_video(&self) -> bool {
+        self.contains(libobs::OBS_OUTPUT_VIDEO)
+    }
+
+    pub fn is_audio(&self) -> bool {
+        self.contains(libobs::OBS_OUTPUT_AUDIO)
+    }
+
+    pub fn is_encoded(&self) -> bool {
+        self.contains(libobs::OBS_OUTPUT_ENCODED)
+    }
+
+    pub fn uses_service(&self) -> bool {
+        self.contains(libobs::OBS_OUTPUT_SERVICE)
+    }
+
+    pub fn is_multi_track(&self) -> bool {
+        self.contains(libobs::OBS_OUTPUT_MULTI_TRACK)
+    }
+
+    pub fn can_pause(&self) -> bool {
+        self.contains(libobs::OBS_OUTPUT_CAN_PAUSE)
+    }
+}
+
+/// A source, filter or transition type registered by a plugin.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[skip_new]
+pub struct ObsSourceTypeInfo {
+    id: String,
+    /// The localized name of the type
+    display_name: String,
+    output_flags: ObsSourceOutputFlags,
+    icon_type: ObsIconType,
+}
+
+/// An output type registered by a plugin.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[skip_new]
+pub struct ObsOutputTypeInfo {
+    id: String,
+    /// The localized name of the type
+    display_name: String,
+    output_flags: ObsOutputFlags,
+}
+
+/// An encoder type registered by a plugin.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[skip_new]
+pub struct ObsEncoderTypeInfo {
+    id: String,
+    /// The localized name of the type
+    display_name: String,
+    encoder_type: ObsEncoderType,
+    /// The capabilities of the encoder (`OBS_ENCODER_CAP_*`)
+    caps: u32,
+}
+
+impl ObsEncoderTypeInfo {
+    /// Deprecated and internal encoders should not be shown to the user.
+    pub fn is_hidden(&self) -> bool {
+        self.caps & super::ENCODER_HIDE_FLAGS != 0
+    }
+}
+
+/// A service type registered by a plugin.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[skip_new]
+pub struct ObsServiceTypeInfo {
+    id: String,
+    /// The localized name of the type
+    display_name: String,
+}
+
+/// The kind of source types to enumerate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ObsSourceKind {
+    Input,
+    Filter,
+    Transition,
+}
+
+type EnumTypesFn = unsafe extern "C" fn(usize, *mut *const c_char) -> bool;
+
+/// Collects the ids of all types of the given enumeration function.
+///
+/// # Safety
+/// Must be called on the OBS thread.
+unsafe fn enum_type_ids(enum_fn: EnumTypesFn) -> Vec<String> {
+    let mut ids = Vec::new();
+    let mut idx = 0;
+    let mut id: *const c_char = std::ptr::null();
+
+    while enum_fn(idx, &mut id) {
+        idx += 1;
+        if id.is_null() {
+            continue;
+        }
+
+        ids.push(CStr::from_ptr(id).to_string_lossy().to_string());
+    }
+
+    ids
+}
+
+unsafe fn to_string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+
+    CStr::from_ptr(ptr).to_string_lossy().to_string()
+}
+
+pub(crate) fn source_types(
+    kind: ObsSourceKind,
+    runtime: &ObsRuntime,
+) -> Result<Vec<ObsSourceTypeInfo>, ObsError> {
+    run_with_obs!(runtime, move || unsafe {
+        let enum_fn: EnumTypesFn = match kind {
+            ObsSourceKind::Input => libobs::obs_enum_input_types,
+            ObsSourceKind::Filter => libobs::obs_enum_filter_types,
+            ObsSourceKind::Transition => libobs::obs_enum_transition_types,
+        };
+
+        enum_type_ids(enum_fn)
+            .into_iter()
+            .map(|id| {
+                let id_str = ObsString::new(&id);
+                let id_ptr = id_str.as_ptr().0;
+
+                let icon_type = libobs::obs_source_get_icon_type(id_ptr);
+                ObsSourceTypeInfo {
+                    display_name: to_string(libobs::obs_source_get_display_name(id_ptr)),
+                    output_flags: ObsSourceOutputFlags(libobs::obs_get_source_output_flags(id_ptr)),
+                    icon_type: ObsIconType::from_i64(icon_type as i64)
+                        .unwrap_or(ObsIconType::Unknown),
+                    id,
+                }
+            })
+            .collect()
+    })
+}
+
+pub(crate) fn output_types(runtime: &ObsRuntime) -> Result<Vec<ObsOutputTypeInfo>, ObsError> {
+    run_with_obs!(runtime, move || unsafe {
+        enum_type_ids(libobs::obs_enum_output_types)
+            .into_iter()
+            .map(|id| {
+                let id_str = ObsString::new(&id);
+                let id_ptr = id_str.as_ptr().0;
+
+                ObsOutputTypeInfo {
+                    display_name: to_string(libobs::obs_output_get_display_name(id_ptr)),
+                    output_flags: ObsOutputFlags(libobs::obs_get_output_flags(id_ptr)),
+                    id,
+                }
+            })
+            .collect()
+    })
+}
+
+pub(crate) fn encoder_types(runtime: &ObsRuntime) -> Result<Vec<ObsEncoderTypeInfo>, ObsError> {
+    run_with_obs!(runtime, move || unsafe {
+        enum_type_ids(libobs::obs_enum_encoder_types)
+            .into_iter()
+            .filter_map(|id| {
+                let id_str = ObsString::new(&id);
+                let id_ptr = id_str.as_ptr().0;
+
+                let encoder_type = libobs::obs_get_encoder_type(id_ptr);
+                let encoder_type = ObsEncoderType::from_i64(encoder_type as i64)?;
+
+                Some(ObsEncoderTypeInfo {
+                    display_name: to_string(libobs::obs_encoder_get_display_name(id_ptr)),
+                    encoder_type,
+                    caps: libobs::obs_get_encoder_caps(id_ptr),
+                    id,
+                })
+            })
+            .collect()
+    })
+}
+
+pub(crate) fn service_types(runtime: &ObsRuntime) -> Result<Vec<ObsServiceTypeInfo>, ObsError> {
+    run_with_obs!(runtime, move || unsafe {
+        enum_type_ids(libobs::obs_enum_service_types)
+            .into_iter()
+            .map(|id| {
+                let id_str = ObsString::new(&id);
+                let id_ptr = id_str.as_ptr().0;
+
+                ObsServiceTypeInfo {
+                    display_name: to_string(libobs::obs_service_get_display_name(id_ptr)),
+                    id,
+                }
+            })
+            .collect()
+    })
+}